@@ -1,282 +1,495 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+
+use tui::style::Color;
+use unicode_width::UnicodeWidthStr;
+
+use crate::data::{IconSVG, Symboles};
+use crate::storage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AsciiIconType {
     Ensoleille,
     Nuageux,
+    Lune,
+    LuneNuageux,
     Couvert,
     Brume,
     Pluie,
+    PluieVerglacante,
     Averses,
     Orages,
+    OrageGrele,
+    Neige,
+    Grele,
+    VentDeSable,
+}
+
+/// Table des pictogrammes de l'API Météo France. Chaque code de base
+/// existe en version jour (suffixe "j") et nuit (suffixe "n"), certains
+/// sont aussi envoyés sans suffixe : le suffixe est retiré avant la
+/// recherche dans la table (voir decomposer_code).
+const PICTOGRAMMES: &[(&str, AsciiIconType)] = &[
+    // Ciel clair, voilé
+    ("p1", AsciiIconType::Ensoleille),
+    ("p1bis", AsciiIconType::Ensoleille),
+    // Éclaircies
+    ("p2", AsciiIconType::Nuageux),
+    ("p2bis", AsciiIconType::Nuageux),
+    // Très nuageux, couvert
+    ("p3", AsciiIconType::Couvert),
+    ("p3bis", AsciiIconType::Couvert),
+    ("p4", AsciiIconType::Couvert),
+    ("p4bis", AsciiIconType::Couvert),
+    // Brume, brouillard, brouillard givrant
+    ("p5", AsciiIconType::Brume),
+    ("p5bis", AsciiIconType::Brume),
+    ("p5ter", AsciiIconType::Brume),
+    ("p6", AsciiIconType::Brume),
+    ("p6bis", AsciiIconType::Brume),
+    ("p6ter", AsciiIconType::Brume),
+    ("p7", AsciiIconType::Brume),
+    ("p7bis", AsciiIconType::Brume),
+    ("p8", AsciiIconType::Brume),
+    ("p8bis", AsciiIconType::Brume),
+    // Bruine, pluie faible à forte
+    ("p9", AsciiIconType::Pluie),
+    ("p9bis", AsciiIconType::Pluie),
+    ("p10", AsciiIconType::Pluie),
+    ("p10bis", AsciiIconType::Pluie),
+    ("p11", AsciiIconType::Pluie),
+    ("p11bis", AsciiIconType::Pluie),
+    ("p12", AsciiIconType::Pluie),
+    ("p12bis", AsciiIconType::Pluie),
+    ("p13", AsciiIconType::Pluie),
+    ("p13bis", AsciiIconType::Pluie),
+    // Bruine et pluie verglaçantes
+    ("p13ter", AsciiIconType::PluieVerglacante),
+    ("p13quater", AsciiIconType::PluieVerglacante),
+    // Averses
+    ("p14", AsciiIconType::Averses),
+    ("p14bis", AsciiIconType::Averses),
+    ("p14ter", AsciiIconType::Averses),
+    ("p14quater", AsciiIconType::Averses),
+    ("p15", AsciiIconType::Averses),
+    ("p15bis", AsciiIconType::Averses),
+    // Orages isolés
+    ("p16", AsciiIconType::Orages),
+    ("p16bis", AsciiIconType::Orages),
+    // Neige, averses de neige, pluie et neige mêlées
+    ("p17", AsciiIconType::Neige),
+    ("p17bis", AsciiIconType::Neige),
+    ("p18", AsciiIconType::Neige),
+    ("p18bis", AsciiIconType::Neige),
+    ("p18ter", AsciiIconType::Neige),
+    ("p19", AsciiIconType::Neige),
+    ("p19bis", AsciiIconType::Neige),
+    ("p20", AsciiIconType::Neige),
+    ("p20bis", AsciiIconType::Neige),
+    ("p20ter", AsciiIconType::Neige),
+    ("p21", AsciiIconType::Neige),
+    ("p21bis", AsciiIconType::Neige),
+    ("p22", AsciiIconType::Neige),
+    ("p22bis", AsciiIconType::Neige),
+    ("p22ter", AsciiIconType::Neige),
+    ("p22quater", AsciiIconType::Neige),
+    // Averses de pluie verglaçante
+    ("p23", AsciiIconType::PluieVerglacante),
+    ("p23bis", AsciiIconType::PluieVerglacante),
+    // Averses de grêle
+    ("p24", AsciiIconType::Grele),
+    ("p24bis", AsciiIconType::Grele),
+    ("p24ter", AsciiIconType::Grele),
+    ("p25", AsciiIconType::Grele),
+    ("p25bis", AsciiIconType::Grele),
+    // Orages
+    ("p26", AsciiIconType::Orages),
+    ("p26bis", AsciiIconType::Orages),
+    ("p27", AsciiIconType::Orages),
+    ("p27bis", AsciiIconType::Orages),
+    // Orages avec grêle
+    ("p28", AsciiIconType::OrageGrele),
+    ("p28bis", AsciiIconType::OrageGrele),
+    ("p29", AsciiIconType::OrageGrele),
+    ("p29bis", AsciiIconType::OrageGrele),
+    // Vent de sable, brume de sable
+    ("p30", AsciiIconType::VentDeSable),
+    ("p30bis", AsciiIconType::VentDeSable),
+    ("p31", AsciiIconType::VentDeSable),
+    ("p32", AsciiIconType::VentDeSable),
+];
+
+/// Nom du fichier SVG (sans extension) de chaque type d'icon dans un pack d'icons
+const FICHIERS_ICONS: &[(&str, AsciiIconType)] = &[
+    ("ensoleille", AsciiIconType::Ensoleille),
+    ("nuageux", AsciiIconType::Nuageux),
+    ("lune", AsciiIconType::Lune),
+    ("lune_nuageux", AsciiIconType::LuneNuageux),
+    ("couvert", AsciiIconType::Couvert),
+    ("brume", AsciiIconType::Brume),
+    ("pluie", AsciiIconType::Pluie),
+    ("pluie_verglacante", AsciiIconType::PluieVerglacante),
+    ("averses", AsciiIconType::Averses),
+    ("orages", AsciiIconType::Orages),
+    ("orage_grele", AsciiIconType::OrageGrele),
+    ("neige", AsciiIconType::Neige),
+    ("grele", AsciiIconType::Grele),
+    ("vent_de_sable", AsciiIconType::VentDeSable),
+];
+
+/// Icons chargés depuis un pack d'icons, qui remplacent ceux du programme
+pub type PackIcons = HashMap<AsciiIconType, Vec<Calque>>;
+
+/// Calques de l'icon correspondant à un code de l'API Météo France,
+/// pris dans le pack d'icons s'il le contient
+pub fn get_calques_icon_from_str(string: String, pack: &PackIcons) -> Vec<Calque> {
+    let which = get_type_icon_from_str(&string);
+    match pack.get(&which) {
+        Some(calques) => calques.clone(),
+        None => get_icon_calques(which),
+    }
+}
+
+/// Type d'icon correspondant au nom d'un fichier d'un pack d'icons
+pub fn get_type_icon_from_nom(nom: &str) -> Option<AsciiIconType> {
+    FICHIERS_ICONS
+        .iter()
+        .find(|(fichier, _)| *fichier == nom)
+        .map(|(_, which)| *which)
+}
+
+/// Calques d'un icon lu depuis un fichier SVG : un calque par tracé, de la
+/// couleur du tracé (blanc si elle est absente ou non reconnue)
+pub fn get_calques_svg(icon: &IconSVG) -> Vec<Calque> {
+    icon.traces
+        .iter()
+        .map(|trace| Calque {
+            couleur: trace
+                .couleur
+                .as_deref()
+                .and_then(get_couleur_svg)
+                .unwrap_or(Color::White),
+            points: trace.points.clone(),
+        })
+        .collect()
+}
+
+/// Couleur SVG : "#rgb", "#rrggbb" ou l'un des noms de couleur courants
+fn get_couleur_svg(couleur: &str) -> Option<Color> {
+    let couleur = couleur.trim().to_lowercase();
+    if let Some(hexa) = couleur.strip_prefix('#') {
+        let composante = |texte: &str| u8::from_str_radix(texte, 16).ok();
+        return match hexa.len() {
+            3 => {
+                let r = composante(&hexa[0..1])?;
+                let g = composante(&hexa[1..2])?;
+                let b = composante(&hexa[2..3])?;
+                Some(Color::Rgb(r * 17, g * 17, b * 17))
+            }
+            6 => Some(Color::Rgb(
+                composante(&hexa[0..2])?,
+                composante(&hexa[2..4])?,
+                composante(&hexa[4..6])?,
+            )),
+            _ => None,
+        };
+    }
+    match couleur.as_str() {
+        "white" => Some(Color::White),
+        "black" => Some(Color::Black),
+        "gray" | "grey" | "silver" => Some(Color::Gray),
+        "darkgray" | "darkgrey" => Some(Color::DarkGray),
+        "red" => Some(Color::Red),
+        "orange" => Some(Color::LightRed),
+        "yellow" | "gold" => Some(Color::Yellow),
+        "green" => Some(Color::Green),
+        "blue" => Some(Color::Blue),
+        "lightblue" | "skyblue" => Some(Color::LightBlue),
+        "cyan" | "aqua" => Some(Color::Cyan),
+        "magenta" | "fuchsia" | "purple" => Some(Color::Magenta),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Un calque d'icon : des points dessinés d'une même couleur
+pub struct Calque {
+    pub couleur: Color,
+    pub points: Vec<(f64, f64)>,
+}
+
+/// Parties d'un icon, chacune dessinée avec sa couleur
+#[derive(Clone, Copy, PartialEq)]
+enum Partie {
+    Soleil,
+    Lune,
+    Nuage,
+    Pluie,
     Neige,
     Grele,
+    Eclair,
+    Glace,
+    Sable,
+}
+
+/// Couleur de chaque partie : des couleurs franches, lisibles sur un
+/// terminal sombre comme sur un terminal clair
+fn get_couleur_partie(partie: Partie) -> Color {
+    match partie {
+        Partie::Soleil => Color::Yellow,
+        Partie::Lune => Color::LightYellow,
+        Partie::Nuage => Color::Gray,
+        Partie::Pluie => Color::Blue,
+        Partie::Neige => Color::LightCyan,
+        Partie::Grele => Color::Cyan,
+        Partie::Eclair => Color::LightRed,
+        Partie::Glace => Color::LightCyan,
+        Partie::Sable => Color::Yellow,
+    }
+}
+
+/// Calques de couleur d'un icon : un calque par partie de get_icon_parties
+pub fn get_icon_calques(which: AsciiIconType) -> Vec<Calque> {
+    get_icon_parties(which)
+        .into_iter()
+        .map(|(partie, points)| calque(partie, points))
+        .collect()
+}
+
+fn calque(partie: Partie, points: Vec<(f64, f64)>) -> Calque {
+    Calque {
+        couleur: get_couleur_partie(partie),
+        points,
+    }
+}
+
+/// Icon compact (une ou deux cellules du terminal) représentant un type
+/// d'icon, pour les endroits où l'on n'a pas la place de le dessiner
+pub fn get_glyphe_icon(which: AsciiIconType, symboles: Symboles) -> &'static str {
+    match symboles {
+        Symboles::Emoji => match which {
+            AsciiIconType::Ensoleille => "☀",
+            AsciiIconType::Nuageux => "⛅",
+            AsciiIconType::Lune => "☾",
+            AsciiIconType::LuneNuageux => "☾☁",
+            AsciiIconType::Couvert => "☁",
+            AsciiIconType::Brume => "🌫",
+            AsciiIconType::Pluie => "🌧",
+            AsciiIconType::PluieVerglacante => "🧊",
+            AsciiIconType::Averses => "🌦",
+            AsciiIconType::Orages => "⛈",
+            AsciiIconType::OrageGrele => "⛈",
+            AsciiIconType::Neige => "❄",
+            AsciiIconType::Grele => "⁂",
+            AsciiIconType::VentDeSable => "💨",
+        },
+        Symboles::Ascii => match which {
+            AsciiIconType::Ensoleille => "O",
+            AsciiIconType::Nuageux => "O~",
+            AsciiIconType::Lune => "C",
+            AsciiIconType::LuneNuageux => "C~",
+            AsciiIconType::Couvert => "~~",
+            AsciiIconType::Brume => "==",
+            AsciiIconType::Pluie => "//",
+            AsciiIconType::PluieVerglacante => "/_",
+            AsciiIconType::Averses => "~/",
+            AsciiIconType::Orages => "!!",
+            AsciiIconType::OrageGrele => "!o",
+            AsciiIconType::Neige => "**",
+            AsciiIconType::Grele => "oo",
+            AsciiIconType::VentDeSable => "::",
+        },
+    }
+}
+
+/// Icon compact complété par des espaces sur deux cellules,
+/// pour que les colonnes qui le suivent restent alignées
+pub fn get_glyphe_aligne(which: AsciiIconType, symboles: Symboles) -> String {
+    let glyphe = get_glyphe_icon(which, symboles);
+    let espaces = 2usize.saturating_sub(UnicodeWidthStr::width(glyphe));
+    format!("{}{}", glyphe, " ".repeat(espaces))
+}
+
+/// Sépare un code de pictogramme en code de base et moment de la journée :
+/// "p5bisn" donne ("p5bis", Some('n')), "p3" donne ("p3", None).
+/// Renvoie None si le code n'a pas la forme p<numéro><variante><j|n>.
+pub fn decomposer_code(code: &str) -> Option<(&str, Option<char>)> {
+    let (base, moment) = match code.chars().last() {
+        Some(c) if c == 'j' || c == 'n' => (&code[..code.len() - 1], Some(c)),
+        _ => (code, None),
+    };
+
+    let reste = base.strip_prefix('p')?;
+    let chiffres = reste.len() - reste.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if chiffres == 0 {
+        return None;
+    }
+    match &reste[chiffres..] {
+        "" | "bis" | "ter" | "quater" => Some((base, moment)),
+        _ => None,
+    }
+}
+
+/// Table des pictogrammes indexée par code de base (construite une seule fois)
+fn get_table_pictogrammes() -> &'static HashMap<&'static str, AsciiIconType> {
+    static TABLE: OnceLock<HashMap<&'static str, AsciiIconType>> = OnceLock::new();
+    TABLE.get_or_init(|| PICTOGRAMMES.iter().cloned().collect())
+}
+
+/// Un code inconnu est noté (une seule fois par session) dans le journal
+/// des pictogrammes inconnus, pour pouvoir le rajouter à la table
+fn signaler_code_inconnu(code: &str) {
+    if code.is_empty() {
+        return;
+    }
+    static SIGNALES: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    let signales = SIGNALES.get_or_init(|| Mutex::new(HashSet::new()));
+    let nouveau = match signales.lock() {
+        Ok(mut s) => s.insert(code.to_string()),
+        Err(_) => false,
+    };
+    if nouveau {
+        let _ = storage::journaliser_pictogramme_inconnu(code);
+    }
+}
+
+/// Version de nuit d'un type d'icon : la lune remplace le soleil
+/// (les autres types n'ont pas de version de nuit)
+pub fn get_version_nuit(which: AsciiIconType) -> AsciiIconType {
+    match which {
+        AsciiIconType::Ensoleille => AsciiIconType::Lune,
+        AsciiIconType::Nuageux => AsciiIconType::LuneNuageux,
+        _ => which,
+    }
 }
 
 /// Ici, on catégorise grâce à l'enum AsciiIconType les différents
-/// types d'icons présents dans l'API Météo France
-pub fn get_coords_icon_from_str(string: String) -> Vec<(f64, f64)> {
-    let trie = vec![
-        (String::from("p1j"), AsciiIconType::Ensoleille),
-        (String::from("p1n"), AsciiIconType::Ensoleille),
-        (String::from("p1bisj"), AsciiIconType::Ensoleille),
-        (String::from("p1bisn"), AsciiIconType::Ensoleille),
-        (String::from("p2n"), AsciiIconType::Nuageux),
-        (String::from("p2j"), AsciiIconType::Nuageux),
-        (String::from("p2bisj"), AsciiIconType::Nuageux),
-        (String::from("p2bisn"), AsciiIconType::Nuageux),
-        (String::from("p3"), AsciiIconType::Couvert),
-        (String::from("p3bis"), AsciiIconType::Couvert),
-        (String::from("p3j"), AsciiIconType::Couvert),
-        (String::from("p3n"), AsciiIconType::Couvert),
-        (String::from("p4j"), AsciiIconType::Couvert),
-        (String::from("p4n"), AsciiIconType::Couvert),
-        (String::from("p4"), AsciiIconType::Couvert),
-        (String::from("p5j"), AsciiIconType::Brume),
-        (String::from("p5n"), AsciiIconType::Brume),
-        (String::from("p5"), AsciiIconType::Brume),
-        (String::from("p6"), AsciiIconType::Brume),
-        (String::from("p6j"), AsciiIconType::Brume),
-        (String::from("p5bisj"), AsciiIconType::Brume),
-        (String::from("p5bisn"), AsciiIconType::Brume),
-        (String::from("p5bis"), AsciiIconType::Brume),
-        (String::from("p6bis"), AsciiIconType::Brume),
-        (String::from("p6ter"), AsciiIconType::Brume),
-        (String::from("p7"), AsciiIconType::Brume),
-        (String::from("p7bis"), AsciiIconType::Brume),
-        (String::from("p8"), AsciiIconType::Brume),
-        (String::from("p8bis"), AsciiIconType::Brume),
-        (String::from("p9"), AsciiIconType::Pluie),
-        (String::from("p10j"), AsciiIconType::Pluie),
-        (String::from("p10n"), AsciiIconType::Pluie),
-        (String::from("p11"), AsciiIconType::Pluie),
-        (String::from("p10bisj"), AsciiIconType::Pluie),
-        (String::from("p10bisn"), AsciiIconType::Pluie),
-        (String::from("p12n"), AsciiIconType::Pluie),
-        (String::from("p12j"), AsciiIconType::Pluie),
-        (String::from("p13"), AsciiIconType::Pluie),
-        (String::from("p12bisj"), AsciiIconType::Pluie),
-        (String::from("p12bisn"), AsciiIconType::Pluie),
-        (String::from("p13bis"), AsciiIconType::Pluie),
-        (String::from("p13ter"), AsciiIconType::Pluie),
-        (String::from("p13quater"), AsciiIconType::Pluie),
-        (String::from("p14"), AsciiIconType::Averses),
-        (String::from("p14bis"), AsciiIconType::Averses),
-        (String::from("p14ter"), AsciiIconType::Averses),
-        (String::from("p14quater"), AsciiIconType::Averses),
-        (String::from("p15"), AsciiIconType::Averses),
-        (String::from("p16"), AsciiIconType::Orages),
-        (String::from("p16bis"), AsciiIconType::Orages),
-        (String::from("p17j"), AsciiIconType::Neige),
-        (String::from("p17n"), AsciiIconType::Neige),
-        (String::from("p18"), AsciiIconType::Neige),
-        (String::from("p17bisj"), AsciiIconType::Neige),
-        (String::from("p17bisn"), AsciiIconType::Neige),
-        (String::from("p18bis"), AsciiIconType::Neige),
-        (String::from("p18ter"), AsciiIconType::Neige),
-        (String::from("p19j"), AsciiIconType::Neige),
-        (String::from("p19n"), AsciiIconType::Neige),
-        (String::from("p20"), AsciiIconType::Neige),
-        (String::from("p19bisj"), AsciiIconType::Neige),
-        (String::from("p19bisn"), AsciiIconType::Neige),
-        (String::from("p20bis"), AsciiIconType::Neige),
-        (String::from("p20ter"), AsciiIconType::Neige),
-        (String::from("p21j"), AsciiIconType::Neige),
-        (String::from("p21n"), AsciiIconType::Neige),
-        (String::from("p22"), AsciiIconType::Neige),
-        (String::from("p21bisj"), AsciiIconType::Neige),
-        (String::from("p21bisn"), AsciiIconType::Neige),
-        (String::from("p22bis"), AsciiIconType::Neige),
-        (String::from("p22ter"), AsciiIconType::Neige),
-        (String::from("p22quater"), AsciiIconType::Neige),
-        (String::from("p23"), AsciiIconType::Neige),
-        (String::from("p25"), AsciiIconType::Grele),
-        (String::from("p24bisj"), AsciiIconType::Grele),
-        (String::from("p24bisn"), AsciiIconType::Grele),
-        (String::from("p24terj"), AsciiIconType::Grele),
-        (String::from("p24tern"), AsciiIconType::Grele),
-        (String::from("p25bis"), AsciiIconType::Grele),
-        (String::from("p26j"), AsciiIconType::Orages),
-        (String::from("p26n"), AsciiIconType::Orages),
-        (String::from("p27"), AsciiIconType::Orages),
-        (String::from("p26bisn"), AsciiIconType::Orages),
-        (String::from("p26bisj"), AsciiIconType::Orages),
-        (String::from("p28j"), AsciiIconType::Orages),
-        (String::from("p28n"), AsciiIconType::Orages),
-        (String::from("p29"), AsciiIconType::Orages),
-        (String::from("p28bisj"), AsciiIconType::Orages),
-        (String::from("p28bisn"), AsciiIconType::Orages),
-    ];
+/// types d'icons présents dans l'API Météo France.
+/// Le moment de la journée est donné par le suffixe du code ("j" ou "n").
+pub fn get_type_icon_from_str(string: &str) -> AsciiIconType {
+    let nuit = match decomposer_code(string) {
+        Some((_, moment)) => moment == Some('n'),
+        None => false,
+    };
+    get_type_icon_moment(string, nuit)
+}
 
-    for t in trie {
-        if t.0.eq(&string) {
-            return get_icon_coords(t.1);
+/// Type d'icon d'un code de l'API Météo France, de jour ou de nuit
+/// selon le paramètre (et non selon le suffixe du code)
+pub fn get_type_icon_moment(string: &str, nuit: bool) -> AsciiIconType {
+    let icon = decomposer_code(string).and_then(|(base, _)| get_table_pictogrammes().get(base));
+    let icon = match icon {
+        Some(icon) => *icon,
+        None => {
+            signaler_code_inconnu(string);
+            AsciiIconType::Nuageux
         }
+    };
+    if nuit {
+        return get_version_nuit(icon);
     }
+    icon
+}
 
-    get_icon_coords(AsciiIconType::Nuageux)
+/// Tracé de la France métropolitaine et de la Corse au format GeoJSON
+/// (fichier assets/france.geojson, inclus dans le programme)
+pub fn get_france_geojson() -> &'static str {
+    include_str!("../assets/france.geojson")
 }
 
-/// Cette fonction permet de récupérer les coordonnées
-/// de la carte de France
-pub fn get_france_coords() -> Vec<(f64, f64)> {
-    return vec![
-        (153.00000190734863, 126.99999809265137),
-        (149.34173583984375, 122.67318725585938),
-        (147.13201522827148, 117.52721786499023),
-        (145.29891967773438, 112.08737373352051),
-        (144.99999046325684, 106.44296646118164),
-        (144.49135780334473, 102.99999237060547),
-        (139.87744331359863, 101.41094207763672),
-        (138.06716918945312, 101.99688911437988),
-        (136.45211219787598, 97.55467414855957),
-        (132.5710391998291, 94.18185234069824),
-        (131.6757106781006, 88.76596450805664),
-        (129.27228927612305, 84.27206039428711),
-        (134.57002639770508, 85.93916893005371),
-        (137.70424842834473, 82.12965965270996),
-        (139.52561378479004, 77.63039588928223),
-        (137.0081901550293, 73.8422966003418),
-        (139.96521949768066, 70.00013828277588),
-        (139.2177391052246, 65.40011882781982),
-        (134.15600776672363, 63.52218151092529),
-        (137.01202392578125, 59.9476957321167),
-        (138.11201095581055, 55.87057590484619),
-        (138.16160202026367, 51.464481353759766),
-        (141.08783721923828, 49.55883026123047),
-        (146.08797073364258, 49.20330047607422),
-        (146.42593383789062, 45.09143352508545),
-        (143.75487327575684, 40.76851844787598),
-        (141.99252128601074, 40.01511096954346),
-        (137.9999828338623, 37.534024715423584),
-        (137.8715705871582, 34.47798490524292),
-        (133.82067680358887, 33.413963317871094),
-        (130.43395042419434, 32.34369993209839),
-        (126.73831939697266, 33.261659145355225),
-        (123.67526054382324, 35.939462184906006),
-        (119.99997138977051, 36.36852025985718),
-        (118.19967269897461, 34.777469635009766),
-        (114.17401313781738, 37.00000047683716),
-        (109.4149112701416, 37.60995626449585),
-        (104.64040756225586, 34.41789388656616),
-        (101.47557258605957, 33.46982002258301),
-        (96.08502388000488, 31.380577087402344),
-        (95.7642936706543, 25.76961040496826),
-        (96.92471504211426, 21.752784252166748),
-        (91.60038948059082, 21.390490531921387),
-        (87.81652450561523, 21.11715078353882),
-        (82.87622451782227, 21.444590091705322),
-        (79.12109375, 24.687836170196533),
-        (74.92359638214111, 25.89442014694214),
-        (69.82669353485107, 27.865006923675537),
-        (67.5339937210083, 25.438034534454346),
-        (62.25388526916504, 24.911141395568848),
-        (57.276244163513184, 26.424214839935303),
-        (53.11389923095703, 25.886108875274658),
-        (51.9451904296875, 29.629881381988525),
-        (46.9995641708374, 30.97506046295166),
-        (44.71058368682861, 33.82326364517212),
-        (40.068349838256836, 35.99838972091675),
-        (43.332839012145996, 39.45044279098511),
-        (44.88487720489502, 45.11557579040527),
-        (46.046080589294434, 50.8290433883667),
-        (46.447415351867676, 56.69210433959961),
-        (47.04383850097656, 62.53873825073242),
-        (47.87710666656494, 68.35568904876709),
-        (46.5037202835083, 70.03849983215332),
-        (46.58824920654297, 72.65864372253418),
-        (45.02953052520752, 78.17579746246338),
-        (48.350830078125, 78.00364971160889),
-        (44.84191417694092, 79.97788906097412),
-        (46.04124069213867, 81.2688159942627),
-        (41.80380344390869, 83.83753776550293),
-        (38.89274597167969, 88.7325668334961),
-        (35.38640737533569, 92.8367805480957),
-        (38.022756576538086, 94.2642593383789),
-        (36.95926904678345, 96.74617767333984),
-        (32.12076187133789, 97.99525260925293),
-        (32.34848737716675, 101.93392753601074),
-        (27.51030683517456, 103.39474678039551),
-        (25.64445972442627, 101.92577362060547),
-        (23.117802143096924, 105.00483512878418),
-        (17.84446120262146, 106.15532875061035),
-        (13.603172302246094, 106.07697486877441),
-        (10.371837615966797, 110.07060050964355),
-        (7.330765128135681, 111.44359588623047),
-        (11.106247901916504, 114.52234268188477),
-        (8.320923447608948, 118.43478202819824),
-        (11.016302108764648, 122.5839900970459),
-        (16.037955284118652, 123.88424873352051),
-        (19.909547567367554, 123.82765769958496),
-        (21.779956817626953, 124.69820976257324),
-        (26.441850662231445, 125.51352500915527),
-        (29.689581394195557, 124.05101776123047),
-        (31.76598310470581, 119.54323768615723),
-        (35.052621364593506, 121.05262756347656),
-        (38.42965841293335, 122.04126358032227),
-        (42.10289001464844, 120.88264465332031),
-        (41.59590244293213, 124.19181823730469),
-        (40.87378978729248, 129.81233596801758),
-        (38.46155881881714, 134.46242332458496),
-        (38.76119375228882, 139.12135124206543),
-        (42.954463958740234, 138.5938549041748),
-        (47.66942024230957, 136.9749641418457),
-        (49.905762672424316, 134.27814483642578),
-        (55.66483020782471, 133.1661033630371),
-        (61.259117126464844, 133.00602912902832),
-        (62.09075927734375, 138.32274436950684),
-        (64.56559658050537, 140.3567600250244),
-        (69.72041130065918, 142.9918098449707),
-        (75.17309665679932, 145.01922607421875),
-        (78.9163875579834, 149.09399032592773),
-        (79.00000095367432, 154.86688613891602),
-        (78.7808084487915, 159.99943733215332),
-        (83.01684379577637, 161.81289672851562),
-        (85.68012237548828, 162.82611846923828),
-        (89.20198440551758, 162.22414016723633),
-        (92.28989601135254, 158.42019081115723),
-        (97.00535774230957, 157.87301063537598),
-        (99.52889442443848, 153.19857597351074),
-        (103.29821586608887, 151.99844360351562),
-        (107.65253067016602, 150.41282653808594),
-        (109.00001525878906, 146.0336399078369),
-        (111.3355827331543, 143.58888626098633),
-        (115.48324584960938, 147.00207710266113),
-        (116.39870643615723, 144.61259841918945),
-        (118.4840202331543, 141.86058044433594),
-        (122.35204696655273, 138.85626792907715),
-        (126.69854164123535, 137.705717086792),
-        (130.8463191986084, 136.64950370788574),
-        (134.90921020507812, 133.59564781188965),
-        (138.86993408203125, 131.3928508758545),
-        (143.11168670654297, 130.98444938659668),
-        (147.31118202209473, 128.23638916015625),
-        (152.7619743347168, 127.01569557189941),
-        (51.47622585296631, 68.73020648956299),
-        (11.13947868347168, 112.11156845092773),
-        (129.94327545166016, 80.99985122680664),
-        (27.000000476837158, 100.0),
-        (25.493369102478027, 98.40977668762207),
-        (166.00000381469727, 15.0),
-        (167.49814987182617, 20.70748805999756),
-        (166.16504669189453, 26.38056755065918),
-        (165.0300407409668, 31.448187828063965),
-        (164.34839248657227, 26.656358242034912),
-        (159.09210205078125, 25.237045288085938),
-        (155.5580997467041, 22.741355895996094),
-        (155.5167293548584, 19.34964418411255),
-        (155.99884033203125, 17.04034686088562),
-        (156.29895210266113, 14.651837348937988),
-        (155.46338081359863, 11.969536542892456),
-        (158.24684143066406, 9.47097659111023),
-        (161.89998626708984, 7.000070810317993),
-        (165.29369354248047, 9.767490029335022),
-    ];
+/// Contours simplifiés des départements d'outre-mer,
+/// en (latitude, longitude) : ils sont projetés dans leur encart
+pub fn get_outremer_coords(territoire: &str) -> Vec<(f64, f64)> {
+    match territoire {
+        // Basse-Terre et Grande-Terre, reliées à Pointe-à-Pitre
+        "Guadeloupe" => vec![
+            (16.35, -61.75),
+            (16.23, -61.79),
+            (16.05, -61.77),
+            (15.95, -61.70),
+            (15.98, -61.60),
+            (16.10, -61.57),
+            (16.22, -61.54),
+            (16.24, -61.50),
+            (16.22, -61.35),
+            (16.25, -61.18),
+            (16.33, -61.20),
+            (16.43, -61.40),
+            (16.51, -61.47),
+            (16.38, -61.53),
+            (16.32, -61.56),
+            (16.35, -61.65),
+        ],
+        "Martinique" => vec![
+            (14.88, -61.18),
+            (14.80, -61.23),
+            (14.62, -61.16),
+            (14.60, -61.08),
+            (14.47, -61.08),
+            (14.40, -60.97),
+            (14.40, -60.85),
+            (14.52, -60.82),
+            (14.60, -60.82),
+            (14.75, -60.88),
+            (14.85, -60.95),
+            (14.88, -61.05),
+        ],
+        "Guyane" => vec![
+            (5.75, -54.00),
+            (5.55, -53.30),
+            (5.15, -52.60),
+            (4.85, -52.20),
+            (4.40, -51.80),
+            (4.10, -51.62),
+            (3.60, -51.80),
+            (3.10, -52.10),
+            (2.50, -52.55),
+            (2.15, -52.95),
+            (2.30, -53.80),
+            (2.30, -54.40),
+            (3.00, -54.20),
+            (3.70, -54.35),
+            (4.30, -54.45),
+            (5.00, -54.30),
+            (5.50, -54.10),
+        ],
+        "La Réunion" => vec![
+            (-20.87, 55.45),
+            (-20.92, 55.60),
+            (-21.00, 55.72),
+            (-21.12, 55.80),
+            (-21.28, 55.84),
+            (-21.38, 55.72),
+            (-21.39, 55.55),
+            (-21.30, 55.40),
+            (-21.15, 55.28),
+            (-21.02, 55.22),
+            (-20.93, 55.30),
+        ],
+        "Mayotte" => vec![
+            (-12.64, 45.05),
+            (-12.70, 45.15),
+            (-12.78, 45.20),
+            (-12.80, 45.28),
+            (-12.88, 45.22),
+            (-12.99, 45.18),
+            (-12.98, 45.10),
+            (-12.90, 45.08),
+            (-12.80, 45.03),
+            (-12.70, 45.03),
+        ],
+        _ => vec![],
+    }
 }
 
 /// Cette fonction permet de récupérer les coordonnées
 /// de différents types d'illustration des prévisions météo
-/// (un icon pour chaque option de l'enum AsciiIconType)
-pub fn get_icon_coords(which: AsciiIconType) -> Vec<(f64, f64)> {
-    return match which {
-        AsciiIconType::Ensoleille => {
+/// (un icon pour chaque option de l'enum AsciiIconType),
+/// partie par partie (soleil, nuage, précipitations...)
+fn get_icon_parties(which: AsciiIconType) -> Vec<(Partie, Vec<(f64, f64)>)> {
+    match which {
+        AsciiIconType::Ensoleille => vec![(
+            Partie::Soleil,
             vec![
                 (36.500000953674316, 69.00000095367432),
                 (32.967681884765625, 69.00000095367432),
@@ -428,163 +641,172 @@ pub fn get_icon_coords(which: AsciiIconType) -> Vec<(f64, f64)> {
                 (98.81440162658691, 83.37080001831055),
                 (99.92212295532227, 80.00112533569336),
                 (100.69483757019043, 76.53544425964355),
-            ]
-        }
-        AsciiIconType::Nuageux => {
-            vec![
-                (86.00000381469727, 110.50000190734863),
-                (85.0, 113.80317687988281),
-                (85.0, 117.34555244445801),
-                (85.0, 120.88791847229004),
-                (85.01398086547852, 124.43007469177246),
-                (87.0637035369873, 127.00944900512695),
-                (90.52060127258301, 127.36978530883789),
-                (92.73990631103516, 124.85544204711914),
-                (93.0000114440918, 121.34439468383789),
-                (93.0000114440918, 117.8020191192627),
-                (93.0000114440918, 114.25965309143066),
-                (92.3213005065918, 110.86363792419434),
-                (89.18238639831543, 109.50258255004883),
-                (50.999999046325684, 106.49999618530273),
-                (50.00092029571533, 109.78910446166992),
-                (51.42538070678711, 112.80583381652832),
-                (54.81411933898926, 113.47997665405273),
-                (57.68392562866211, 111.70208930969238),
-                (59.96471881866455, 109.04116630554199),
-                (60.94766616821289, 105.92104911804199),
-                (59.475440979003906, 102.77963638305664),
-                (56.233625411987305, 101.92252159118652),
-                (53.47815990447998, 104.0218448638916),
-                (113.00000190734863, 98.99999618530273),
-                (112.01750755310059, 102.33013153076172),
-                (113.51855278015137, 105.48398017883301),
-                (116.12837791442871, 107.9198169708252),
-                (118.73821258544922, 110.35566329956055),
-                (121.54579162597656, 112.50164985656738),
-                (125.02838134765625, 112.86775588989258),
-                (127.25155830383301, 110.32176971435547),
-                (127.1254825592041, 106.83756828308105),
-                (124.7177791595459, 104.2177677154541),
-                (122.19344139099121, 101.69342994689941),
-                (119.66911315917969, 99.16910171508789),
-                (116.3605785369873, 98.00017356872559),
-                (115.50000190734863, 74.49999809265137),
-                (115.34490585327148, 71.05096340179443),
-                (114.82095718383789, 67.63929843902588),
-                (113.84162902832031, 64.3306016921997),
-                (112.34982490539551, 61.22012138366699),
-                (110.35284042358398, 58.407273292541504),
-                (111.00301742553711, 55.26470184326172),
-                (111.74647331237793, 51.89537525177002),
-                (111.99706077575684, 48.453073501586914),
-                (111.7802619934082, 45.0100040435791),
-                (111.02426528930664, 41.64311408996582),
-                (109.84465599060059, 38.39881420135498),
-                (108.38312149047852, 35.27029275894165),
-                (106.51533126831055, 32.37150430679321),
-                (104.13743019104004, 29.87281560897827),
-                (101.41242980957031, 27.754170894622803),
-                (98.49698066711426, 25.904479026794434),
-                (95.37266731262207, 24.44011926651001),
-                (92.05763816833496, 23.484177589416504),
-                (88.6347484588623, 23.04612398147583),
-                (85.18181800842285, 23.000001907348633),
-                (81.72801971435547, 23.000001907348633),
-                (78.27422142028809, 23.000001907348633),
-                (74.8204231262207, 23.000001907348633),
-                (71.36663436889648, 22.999999523162842),
-                (67.9128360748291, 22.999999523162842),
-                (64.45903778076172, 22.999999523162842),
-                (61.005239486694336, 22.999999523162842),
-                (57.55144119262695, 22.999999523162842),
-                (54.09764289855957, 22.999999523162842),
-                (50.64384460449219, 22.999999523162842),
-                (47.190046310424805, 22.999999523162842),
-                (43.73624801635742, 22.99999713897705),
-                (40.28244972229004, 22.99999713897705),
-                (36.828649044036865, 22.99999713897705),
-                (33.37485074996948, 22.99999713897705),
-                (29.932608604431152, 23.231213092803955),
-                (26.568195819854736, 23.998281955718994),
-                (23.326716423034668, 25.185725688934326),
-                (20.200650691986084, 26.65252447128296),
-                (17.279154062271118, 28.48712921142578),
-                (14.720969200134277, 30.800631046295166),
-                (12.615553140640259, 33.53287696838379),
-                (10.892337560653687, 36.524555683135986),
-                (9.431374073028564, 39.650468826293945),
-                (8.479273319244385, 42.96659469604492),
-                (8.044724464416504, 46.38994216918945),
-                (8.064445853233337, 49.84139919281006),
-                (8.551051616668701, 53.25772762298584),
-                (9.531924724578857, 56.56602382659912),
-                (10.981851816177368, 59.69756603240967),
-                (12.83296823501587, 62.61082172393799),
-                (15.027743577957153, 65.27400016784668),
-                (17.59825110435486, 67.57541179656982),
-                (20.495030879974365, 69.45006370544434),
-                (23.627939224243164, 70.89813709259033),
-                (26.90805435180664, 71.97475910186768),
-                (27.87201166152954, 75.24166107177734),
-                (29.136414527893066, 78.45348834991455),
-                (30.781495571136475, 81.48781776428223),
-                (32.78662443161011, 84.29733276367188),
-                (35.10677099227905, 86.85331344604492),
-                (37.68455982208252, 89.14978981018066),
-                (40.485782623291016, 91.16708755493164),
-                (43.50509166717529, 92.83950805664062),
-                (46.71271800994873, 94.11351203918457),
-                (50.057334899902344, 94.96570587158203),
-                (53.480591773986816, 95.40961265563965),
-                (56.931986808776855, 95.4826545715332),
-                (60.36433219909668, 95.1220703125),
-                (63.72321128845215, 94.32710647583008),
-                (66.97230815887451, 93.16025733947754),
-                (69.97957706451416, 92.47065544128418),
-                (72.58376598358154, 94.73697662353516),
-                (75.42169094085693, 96.70193672180176),
-                (78.4791612625122, 98.30320358276367),
-                (81.72120094299316, 99.48620796203613),
-                (85.09383201599121, 100.21812438964844),
-                (88.53397369384766, 100.49599647521973),
-                (91.98366165161133, 100.36624908447266),
-                (95.38836479187012, 99.80481147766113),
-                (98.64509582519531, 98.67175102233887),
-                (101.76718711853027, 97.1967887878418),
-                (104.70768928527832, 95.39076805114746),
-                (107.33930587768555, 93.15916061401367),
-                (109.43532943725586, 90.41762351989746),
-                (111.32428169250488, 87.52695083618164),
-                (112.99687385559082, 84.50621604919434),
-                (114.22335624694824, 81.28293037414551),
-                (114.97495651245117, 77.91348457336426),
-                (126.49999618530273, 43.00000190734863),
-                (127.49881744384766, 39.68872547149658),
-                (126.0185718536377, 36.74330949783325),
-                (122.66281127929688, 36.00794315338135),
-                (119.7559642791748, 37.637736797332764),
-                (117.09656715393066, 39.9172306060791),
-                (116.0042667388916, 43.159847259521484),
-                (117.69608497619629, 46.0978889465332),
-                (121.03043556213379, 46.99786186218262),
-                (124.02324676513672, 45.476746559143066),
-                (141.99999809265137, 74.49999809265137),
-                (141.04390144348145, 71.52262687683105),
-                (137.93018341064453, 70.49999713897705),
-                (134.61960792541504, 70.49999713897705),
-                (131.30903244018555, 70.49999713897705),
-                (127.99846649169922, 70.49999713897705),
-                (124.9691390991211, 71.53109073638916),
-                (123.5013198852539, 74.41098690032959),
-                (124.84529495239258, 77.34099864959717),
-                (127.89737701416016, 78.4982442855835),
-                (131.20792388916016, 78.49999904632568),
-                (134.51849937438965, 78.49999904632568),
-                (137.82907485961914, 78.49999904632568),
-                (140.92802047729492, 77.56875038146973),
-            ]
-        }
-        AsciiIconType::Couvert => {
+            ],
+        )],
+        AsciiIconType::Nuageux => vec![
+            (
+                Partie::Soleil,
+                vec![
+                    (86.00000381469727, 110.50000190734863),
+                    (85.0, 113.80317687988281),
+                    (85.0, 117.34555244445801),
+                    (85.0, 120.88791847229004),
+                    (85.01398086547852, 124.43007469177246),
+                    (87.0637035369873, 127.00944900512695),
+                    (90.52060127258301, 127.36978530883789),
+                    (92.73990631103516, 124.85544204711914),
+                    (93.0000114440918, 121.34439468383789),
+                    (93.0000114440918, 117.8020191192627),
+                    (93.0000114440918, 114.25965309143066),
+                    (92.3213005065918, 110.86363792419434),
+                    (89.18238639831543, 109.50258255004883),
+                    (50.999999046325684, 106.49999618530273),
+                    (50.00092029571533, 109.78910446166992),
+                    (51.42538070678711, 112.80583381652832),
+                    (54.81411933898926, 113.47997665405273),
+                    (57.68392562866211, 111.70208930969238),
+                    (59.96471881866455, 109.04116630554199),
+                    (60.94766616821289, 105.92104911804199),
+                    (59.475440979003906, 102.77963638305664),
+                    (56.233625411987305, 101.92252159118652),
+                    (53.47815990447998, 104.0218448638916),
+                    (113.00000190734863, 98.99999618530273),
+                    (112.01750755310059, 102.33013153076172),
+                    (113.51855278015137, 105.48398017883301),
+                    (116.12837791442871, 107.9198169708252),
+                    (118.73821258544922, 110.35566329956055),
+                    (121.54579162597656, 112.50164985656738),
+                    (125.02838134765625, 112.86775588989258),
+                    (127.25155830383301, 110.32176971435547),
+                    (127.1254825592041, 106.83756828308105),
+                    (124.7177791595459, 104.2177677154541),
+                    (122.19344139099121, 101.69342994689941),
+                    (119.66911315917969, 99.16910171508789),
+                    (116.3605785369873, 98.00017356872559),
+                    (78.4791612625122, 98.30320358276367),
+                    (81.72120094299316, 99.48620796203613),
+                    (85.09383201599121, 100.21812438964844),
+                    (88.53397369384766, 100.49599647521973),
+                    (91.98366165161133, 100.36624908447266),
+                    (95.38836479187012, 99.80481147766113),
+                    (98.64509582519531, 98.67175102233887),
+                    (126.49999618530273, 43.00000190734863),
+                    (127.49881744384766, 39.68872547149658),
+                    (126.0185718536377, 36.74330949783325),
+                    (122.66281127929688, 36.00794315338135),
+                    (121.03043556213379, 46.99786186218262),
+                    (124.02324676513672, 45.476746559143066),
+                    (141.99999809265137, 74.49999809265137),
+                    (141.04390144348145, 71.52262687683105),
+                    (137.93018341064453, 70.49999713897705),
+                    (134.61960792541504, 70.49999713897705),
+                    (131.30903244018555, 70.49999713897705),
+                    (127.99846649169922, 70.49999713897705),
+                    (124.9691390991211, 71.53109073638916),
+                    (123.5013198852539, 74.41098690032959),
+                    (124.84529495239258, 77.34099864959717),
+                    (127.89737701416016, 78.4982442855835),
+                    (131.20792388916016, 78.49999904632568),
+                    (134.51849937438965, 78.49999904632568),
+                    (137.82907485961914, 78.49999904632568),
+                    (140.92802047729492, 77.56875038146973),
+                ],
+            ),
+            (
+                Partie::Nuage,
+                vec![
+                    (115.50000190734863, 74.49999809265137),
+                    (115.34490585327148, 71.05096340179443),
+                    (114.82095718383789, 67.63929843902588),
+                    (113.84162902832031, 64.3306016921997),
+                    (112.34982490539551, 61.22012138366699),
+                    (110.35284042358398, 58.407273292541504),
+                    (111.00301742553711, 55.26470184326172),
+                    (111.74647331237793, 51.89537525177002),
+                    (111.99706077575684, 48.453073501586914),
+                    (111.7802619934082, 45.0100040435791),
+                    (111.02426528930664, 41.64311408996582),
+                    (109.84465599060059, 38.39881420135498),
+                    (108.38312149047852, 35.27029275894165),
+                    (106.51533126831055, 32.37150430679321),
+                    (104.13743019104004, 29.87281560897827),
+                    (101.41242980957031, 27.754170894622803),
+                    (98.49698066711426, 25.904479026794434),
+                    (95.37266731262207, 24.44011926651001),
+                    (92.05763816833496, 23.484177589416504),
+                    (88.6347484588623, 23.04612398147583),
+                    (85.18181800842285, 23.000001907348633),
+                    (81.72801971435547, 23.000001907348633),
+                    (78.27422142028809, 23.000001907348633),
+                    (74.8204231262207, 23.000001907348633),
+                    (71.36663436889648, 22.999999523162842),
+                    (67.9128360748291, 22.999999523162842),
+                    (64.45903778076172, 22.999999523162842),
+                    (61.005239486694336, 22.999999523162842),
+                    (57.55144119262695, 22.999999523162842),
+                    (54.09764289855957, 22.999999523162842),
+                    (50.64384460449219, 22.999999523162842),
+                    (47.190046310424805, 22.999999523162842),
+                    (43.73624801635742, 22.99999713897705),
+                    (40.28244972229004, 22.99999713897705),
+                    (36.828649044036865, 22.99999713897705),
+                    (33.37485074996948, 22.99999713897705),
+                    (29.932608604431152, 23.231213092803955),
+                    (26.568195819854736, 23.998281955718994),
+                    (23.326716423034668, 25.185725688934326),
+                    (20.200650691986084, 26.65252447128296),
+                    (17.279154062271118, 28.48712921142578),
+                    (14.720969200134277, 30.800631046295166),
+                    (12.615553140640259, 33.53287696838379),
+                    (10.892337560653687, 36.524555683135986),
+                    (9.431374073028564, 39.650468826293945),
+                    (8.479273319244385, 42.96659469604492),
+                    (8.044724464416504, 46.38994216918945),
+                    (8.064445853233337, 49.84139919281006),
+                    (8.551051616668701, 53.25772762298584),
+                    (9.531924724578857, 56.56602382659912),
+                    (10.981851816177368, 59.69756603240967),
+                    (12.83296823501587, 62.61082172393799),
+                    (15.027743577957153, 65.27400016784668),
+                    (17.59825110435486, 67.57541179656982),
+                    (20.495030879974365, 69.45006370544434),
+                    (23.627939224243164, 70.89813709259033),
+                    (26.90805435180664, 71.97475910186768),
+                    (27.87201166152954, 75.24166107177734),
+                    (29.136414527893066, 78.45348834991455),
+                    (30.781495571136475, 81.48781776428223),
+                    (32.78662443161011, 84.29733276367188),
+                    (35.10677099227905, 86.85331344604492),
+                    (37.68455982208252, 89.14978981018066),
+                    (40.485782623291016, 91.16708755493164),
+                    (43.50509166717529, 92.83950805664062),
+                    (46.71271800994873, 94.11351203918457),
+                    (50.057334899902344, 94.96570587158203),
+                    (53.480591773986816, 95.40961265563965),
+                    (56.931986808776855, 95.4826545715332),
+                    (60.36433219909668, 95.1220703125),
+                    (63.72321128845215, 94.32710647583008),
+                    (66.97230815887451, 93.16025733947754),
+                    (69.97957706451416, 92.47065544128418),
+                    (72.58376598358154, 94.73697662353516),
+                    (75.42169094085693, 96.70193672180176),
+                    (101.76718711853027, 97.1967887878418),
+                    (104.70768928527832, 95.39076805114746),
+                    (107.33930587768555, 93.15916061401367),
+                    (109.43532943725586, 90.41762351989746),
+                    (111.32428169250488, 87.52695083618164),
+                    (112.99687385559082, 84.50621604919434),
+                    (114.22335624694824, 81.28293037414551),
+                    (114.97495651245117, 77.91348457336426),
+                    (119.7559642791748, 37.637736797332764),
+                    (117.09656715393066, 39.9172306060791),
+                    (116.0042667388916, 43.159847259521484),
+                    (117.69608497619629, 46.0978889465332),
+                ],
+            ),
+        ],
+        AsciiIconType::Couvert => vec![(
+            Partie::Nuage,
             vec![
                 (126.49999618530273, 61.500000953674316),
                 (126.43842697143555, 59.59599018096924),
@@ -736,9 +958,10 @@ pub fn get_icon_coords(which: AsciiIconType) -> Vec<(f64, f64)> {
                 (126.09853744506836, 67.16366767883301),
                 (126.47934913635254, 65.29786109924316),
                 (126.62446975708008, 63.39948654174805),
-            ]
-        }
-        AsciiIconType::Brume => {
+            ],
+        )],
+        AsciiIconType::Brume => vec![(
+            Partie::Nuage,
             vec![
                 (17.5, 70.99999904632568),
                 (20.639147758483887, 69.18724060058594),
@@ -890,777 +1113,1027 @@ pub fn get_icon_coords(which: AsciiIconType) -> Vec<(f64, f64)> {
                 (52.06858158111572, 55.5991268157959),
                 (50.134291648864746, 52.59260654449463),
                 (50.38066864013672, 48.97197723388672),
-            ]
+            ],
+        )],
+        AsciiIconType::Pluie => vec![
+            (
+                Partie::Pluie,
+                vec![
+                    (75.99999904632568, 32.00000047683716),
+                    (76.33109092712402, 27.98654317855835),
+                    (75.19199848175049, 23.991069793701172),
+                    (71.47638320922852, 22.50000238418579),
+                    (68.26160430908203, 24.533488750457764),
+                    (68.11460494995117, 28.68767499923706),
+                    (69.43090438842773, 32.59615421295166),
+                    (73.16407680511475, 34.203500747680664),
+                    (56.500000953674316, 17.5),
+                    (56.8422269821167, 13.053334951400757),
+                    (55.21637439727783, 8.868667483329773),
+                    (50.80210208892822, 8.082695007324219),
+                    (48.00023555755615, 11.322021484375),
+                    (48.67741107940674, 15.852278470993042),
+                    (52.114014625549316, 18.651270866394043),
+                    (58.499999046325684, 29.500000476837158),
+                    (59.45066452026367, 25.39973258972168),
+                    (56.547861099243164, 22.661714553833008),
+                    (52.5273323059082, 23.472940921783447),
+                    (51.54438495635986, 27.562124729156494),
+                    (54.41521167755127, 30.32749891281128),
+                    (43.499999046325684, 31.500000953674316),
+                    (43.812899589538574, 27.377395629882812),
+                    (42.54326343536377, 23.40961456298828),
+                    (38.780739307403564, 22.37276792526245),
+                    (35.570409297943115, 24.973294734954834),
+                    (35.17235517501831, 29.034125804901123),
+                    (36.669418811798096, 32.87452459335327),
+                    (40.49798011779785, 34.00100231170654),
+                    (60.17472743988037, 34.338717460632324),
+                    (56.018733978271484, 34.288737773895264),
+                ],
+            ),
+            (
+                Partie::Nuage,
+                vec![
+                    (113.50000381469727, 113.00000190734863),
+                    (112.51462936401367, 116.94222450256348),
+                    (123.853759765625, 117.85375595092773),
+                    (120.89689254760742, 114.89688873291016),
+                    (117.5494384765625, 112.49552726745605),
+                    (115.50000190734863, 88.50000381469727),
+                    (115.14161109924316, 84.19084548950195),
+                    (114.04787063598633, 80.00785827636719),
+                    (112.25456237792969, 76.07311248779297),
+                    (109.86652374267578, 72.46684551239014),
+                    (110.72639465332031, 68.47504615783691),
+                    (111.4248275756836, 64.20907497406006),
+                    (111.4093017578125, 59.88428592681885),
+                    (110.63424110412598, 55.63164710998535),
+                    (109.07374382019043, 51.60017967224121),
+                    (106.8382453918457, 47.898311614990234),
+                    (104.09086227416992, 44.55667972564697),
+                    (100.84141731262207, 41.70581340789795),
+                    (97.10504531860352, 39.533162117004395),
+                    (93.01566123962402, 38.133482933044434),
+                    (88.73486518859863, 37.53044366836548),
+                    (84.40621376037598, 37.50000238418579),
+                    (80.07705688476562, 37.50000238418579),
+                    (75.74789047241211, 37.5),
+                    (71.4187240600586, 37.5),
+                    (67.08956718444824, 37.5),
+                    (62.76041030883789, 37.5),
+                    (53.86636257171631, 37.50000476837158),
+                    (49.53720569610596, 37.50000476837158),
+                    (45.20803928375244, 37.50000476837158),
+                    (40.87888240814209, 37.50000238418579),
+                    (36.549715995788574, 37.50000238418579),
+                    (32.22055912017822, 37.50000238418579),
+                    (28.400416374206543, 38.20399045944214),
+                    (24.194562435150146, 39.19924736022949),
+                    (20.26515245437622, 41.00022792816162),
+                    (16.72030210494995, 43.47667217254639),
+                    (13.589555025100708, 46.461172103881836),
+                    (11.004495620727539, 49.926419258117676),
+                    (9.092050790786743, 53.803462982177734),
+                    (7.9169005155563354, 57.963881492614746),
+                    (7.501073479652405, 62.267441749572754),
+                    (7.826253175735474, 66.57899856567383),
+                    (8.919008374214172, 70.76152324676514),
+                    (10.7522714138031, 74.67721462249756),
+                    (13.210043907165527, 78.23387145996094),
+                    (16.343777179718018, 81.21466636657715),
+                    (19.915350675582886, 83.65221977233887),
+                    (23.835346698760986, 85.47924995422363),
+                    (27.224762439727783, 87.47711181640625),
+                    (28.54766845703125, 91.59538269042969),
+                    (30.466363430023193, 95.47134399414062),
+                    (32.95754909515381, 99.00675773620605),
+                    (35.936219692230225, 102.14354515075684),
+                    (39.296414852142334, 104.86845016479492),
+                    (43.00402641296387, 107.09536552429199),
+                    (47.01577186584473, 108.70966911315918),
+                    (51.23405456542969, 109.66361999511719),
+                    (55.54710865020752, 109.99711990356445),
+                    (59.85799789428711, 109.67020034790039),
+                    (64.06736850738525, 108.67344856262207),
+                    (68.11790466308594, 107.15288162231445),
+                    (71.55674934387207, 108.39954376220703),
+                    (75.03910541534424, 110.96526145935059),
+                    (78.86952877044678, 112.97238349914551),
+                    (82.9794692993164, 114.3156623840332),
+                    (87.25752830505371, 114.94510650634766),
+                    (91.58291816711426, 114.90111351013184),
+                    (95.84646224975586, 114.18661117553711),
+                    (99.87343788146973, 112.61589050292969),
+                    (103.69101524353027, 110.58268547058105),
+                    (107.08468437194824, 107.90594100952148),
+                    (109.87345695495605, 104.60591316223145),
+                    (112.05399513244629, 100.86911201477051),
+                    (113.90748023986816, 96.95917129516602),
+                    (115.10156631469727, 92.80449867248535),
+                    (120.39626121520996, 52.10372447967529),
+                    (117.31950759887695, 55.186285972595215),
+                    (116.21818542480469, 59.14417266845703),
+                    (119.40962791442871, 61.82581901550293),
+                    (123.44677925109863, 61.09932899475098),
+                    (58.76730918884277, 116.52064323425293),
+                    (54.659061431884766, 117.23607063293457),
+                ],
+            ),
+            (
+                Partie::Soleil,
+                vec![
+                    (114.74931716918945, 120.33855438232422),
+                    (117.60252952575684, 123.39556694030762),
+                    (120.45574188232422, 126.45257949829102),
+                    (124.42456245422363, 127.47068405151367),
+                    (127.25107192993164, 124.82318878173828),
+                    (126.79278373718262, 120.82717895507812),
+                    (142.5, 88.99999618530273),
+                    (140.62116622924805, 85.57324409484863),
+                    (136.46902084350586, 84.99999046325684),
+                    (132.22370147705078, 84.99999046325684),
+                    (127.98095703125, 85.0448989868164),
+                    (124.65580940246582, 87.51646041870117),
+                    (124.6589183807373, 91.48819923400879),
+                    (128.2363510131836, 93.48867416381836),
+                    (132.4813461303711, 93.4999942779541),
+                    (136.72666549682617, 93.4999942779541),
+                    (140.80645561218262, 92.81828880310059),
+                    (126.49999618530273, 58.00000190734863),
+                    (127.44193077087402, 53.8386344909668),
+                    (124.4288444519043, 51.12898826599121),
+                    (51.500000953674316, 120.50000190734863),
+                    (50.09661674499512, 124.56168174743652),
+                    (52.64862060546875, 128.04621696472168),
+                    (56.97248935699463, 127.63043403625488),
+                    (59.92148399353027, 124.17494773864746),
+                    (61.463356018066406, 120.03108024597168),
+                    (85.50000190734863, 125.0),
+                    (84.49999809265137, 129.49875831604004),
+                    (84.49999809265137, 134.18889045715332),
+                    (84.50202941894531, 138.87901306152344),
+                    (87.14101791381836, 142.23867416381836),
+                    (91.52206420898438, 141.47777557373047),
+                    (93.00000190734863, 137.22195625305176),
+                    (93.00000190734863, 132.53183364868164),
+                    (92.98943519592285, 127.84185409545898),
+                    (90.12279510498047, 124.65099334716797),
+                ],
+            ),
+        ],
+        AsciiIconType::Averses => vec![
+            (
+                Partie::Nuage,
+                vec![
+                    (113.99999618530273, 113.50000381469727),
+                    (112.59758949279785, 117.26492881774902),
+                    (123.79354476928711, 117.79354095458984),
+                    (120.81199645996094, 114.81199264526367),
+                    (117.56057739257812, 112.18749046325684),
+                    (57.649264335632324, 116.06520652770996),
+                    (53.63657474517822, 117.3634147644043),
+                    (120.99075317382812, 51.00792407989502),
+                    (117.67366409301758, 53.851141929626465),
+                    (116.52193069458008, 57.924137115478516),
+                    (119.29622650146484, 60.79075336456299),
+                    (123.40939521789551, 60.087876319885254),
+                    (123.95360946655273, 86.85030937194824),
+                    (114.49999809265137, 95.50000190734863),
+                    (114.98929023742676, 91.3814640045166),
+                    (114.73541259765625, 87.24264144897461),
+                    (113.76080513000488, 83.21069717407227),
+                    (112.15983390808105, 79.38507080078125),
+                    (110.0791072845459, 75.89341163635254),
+                    (112.13248252868652, 72.29321002960205),
+                    (113.44464302062988, 68.3600902557373),
+                    (113.98686408996582, 64.24967765808105),
+                    (113.82326126098633, 60.10375499725342),
+                    (113.12410354614258, 56.01323127746582),
+                    (111.66152954101562, 52.138309478759766),
+                    (109.39362525939941, 48.667540550231934),
+                    (106.61829948425293, 45.58151721954346),
+                    (103.51152420043945, 42.83581256866455),
+                    (99.9436092376709, 40.72336673736572),
+                    (96.05109214782715, 39.2929744720459),
+                    (91.97154998779297, 38.54361295700073),
+                    (87.82112121582031, 38.500003814697266),
+                    (83.66867065429688, 38.500003814697266),
+                    (81.3176441192627, 36.11177921295166),
+                    (72.10988521575928, 36.34203910827637),
+                    (70.05078792572021, 38.000006675720215),
+                    (65.89833736419678, 38.000006675720215),
+                    (55.4725980758667, 36.900930404663086),
+                    (51.45033836364746, 36.99999809265137),
+                    (47.29788780212402, 36.99999809265137),
+                    (36.72264814376831, 36.68257713317871),
+                    (34.19384479522705, 37.99999952316284),
+                    (30.04223108291626, 38.042452335357666),
+                    (25.939061641693115, 38.64419937133789),
+                    (22.013554573059082, 39.97950077056885),
+                    (18.408700227737427, 42.027997970581055),
+                    (15.24503231048584, 44.70877647399902),
+                    (12.474521398544312, 47.79839515686035),
+                    (10.231384038925171, 51.28652572631836),
+                    (8.633005023002625, 55.113229751586914),
+                    (7.724189758300781, 59.15970325469971),
+                    (7.51211404800415, 63.30197334289551),
+                    (7.980884909629822, 67.42285251617432),
+                    (9.160770177841187, 71.39841556549072),
+                    (11.008689403533936, 75.11170864105225),
+                    (13.40104341506958, 78.50152492523193),
+                    (16.31603240966797, 81.45038604736328),
+                    (19.730329513549805, 83.80331039428711),
+                    (23.497984409332275, 85.53903579711914),
+                    (26.723949909210205, 87.47383117675781),
+                    (27.980871200561523, 91.4281177520752),
+                    (29.785747528076172, 95.16360282897949),
+                    (32.1220064163208, 98.59195709228516),
+                    (34.91875886917114, 101.65708541870117),
+                    (38.0817437171936, 104.34338569641113),
+                    (41.584811210632324, 106.56598091125488),
+                    (45.37863254547119, 108.24465751647949),
+                    (49.37644958496094, 109.354248046875),
+                    (53.488402366638184, 109.91164207458496),
+                    (57.637572288513184, 109.94647979736328),
+                    (61.74237251281738, 109.34797286987305),
+                    (65.71675300598145, 108.1559944152832),
+                    (69.51463222503662, 106.51458740234375),
+                    (72.58732318878174, 109.30490493774414),
+                    (75.98941802978516, 111.67900085449219),
+                    (79.76004600524902, 113.39865684509277),
+                    (83.7626838684082, 114.49019432067871),
+                    (87.88249969482422, 114.97677803039551),
+                    (92.03445434570312, 115.03100395202637),
+                    (96.17874145507812, 114.81964111328125),
+                    (100.22652626037598, 113.92439842224121),
+                    (103.99023056030273, 112.1945571899414),
+                    (107.46010780334473, 109.91982460021973),
+                    (110.44064521789551, 107.04020500183105),
+                    (112.68258094787598, 103.5619068145752),
+                    (113.92899513244629, 99.6053695678711),
+                ],
+            ),
+            (
+                Partie::Soleil,
+                vec![
+                    (115.09340286254883, 120.59341430664062),
+                    (118.074951171875, 123.5749626159668),
+                    (121.07150077819824, 126.53571128845215),
+                    (125.10307312011719, 127.45022773742676),
+                    (127.84276008605957, 124.64884757995605),
+                    (126.76837921142578, 120.78160285949707),
+                    (50.50000190734863, 120.50000190734863),
+                    (49.80435848236084, 124.69029426574707),
+                    (53.477210998535156, 126.85754776000977),
+                    (57.593374252319336, 125.90662956237793),
+                    (60.70180416107178, 122.74567604064941),
+                    (61.13163471221924, 118.51333618164062),
+                    (85.50000190734863, 124.49999809265137),
+                    (84.49999809265137, 128.55730056762695),
+                    (84.49999809265137, 132.80597686767578),
+                    (84.49999809265137, 137.0546531677246),
+                    (86.19562149047852, 140.66173553466797),
+                    (90.28562545776367, 141.4553165435791),
+                    (92.92069435119629, 138.49206924438477),
+                    (93.00000190734863, 134.2477035522461),
+                    (93.00000190734863, 129.99903678894043),
+                    (92.86005973815918, 125.76251983642578),
+                    (89.48431015014648, 123.53705406188965),
+                    (126.49999618530273, 56.99999809265137),
+                    (127.97066688537598, 53.07536602020264),
+                    (125.14513969421387, 50.1908016204834),
+                    (141.99999809265137, 88.00000190734863),
+                    (140.05006790161133, 84.54096794128418),
+                    (135.8128547668457, 83.99999618530273),
+                    (131.48944854736328, 83.99999618530273),
+                    (127.17628479003906, 84.11308288574219),
+                    (124.43404197692871, 90.86722373962402),
+                    (128.03056716918945, 92.99999237060547),
+                    (132.35397338867188, 92.99999237060547),
+                    (136.6773796081543, 92.99999237060547),
+                    (140.6785488128662, 91.80417060852051),
+                ],
+            ),
+            (
+                Partie::Pluie,
+                vec![
+                    (80.17687797546387, 32.11910009384155),
+                    (79.03611660003662, 28.126418590545654),
+                    (76.65315628051758, 24.80994939804077),
+                    (72.61176109313965, 24.84755277633667),
+                    (70.50090312957764, 28.22399139404297),
+                    (71.15881443023682, 32.29997396469116),
+                    (63.78399848937988, 35.34059524536133),
+                    (62.70447254180908, 31.330924034118652),
+                    (61.6249418258667, 27.321243286132812),
+                    (60.5454158782959, 23.311572074890137),
+                    (59.4658899307251, 19.30190086364746),
+                    (58.3863639831543, 15.29222846031189),
+                    (57.16548442840576, 11.336570978164673),
+                    (54.24787521362305, 8.607701659202576),
+                    (50.23768424987793, 9.093294739723206),
+                    (49.000000953674316, 12.864872217178345),
+                    (49.93767261505127, 16.890041828155518),
+                    (51.044654846191406, 20.89221954345703),
+                    (52.151641845703125, 24.894397258758545),
+                    (53.258628845214844, 28.89657497406006),
+                    (54.36561584472656, 32.89875268936157),
+                    (45.94107151031494, 33.29374551773071),
+                    (44.80030536651611, 29.301066398620605),
+                    (43.392372131347656, 25.42062759399414),
+                    (39.94706630706787, 23.499999046325684),
+                    (36.18567943572998, 24.683492183685303),
+                    (35.011751651763916, 28.55581760406494),
+                    (35.86719989776611, 32.619194984436035),
+                ],
+            ),
+        ],
+        AsciiIconType::Orages => vec![
+            (
+                Partie::Pluie,
+                vec![
+                    (57.5, 20.999999046325684),
+                    (57.722816467285156, 16.576050519943237),
+                    (56.48045063018799, 12.141764163970947),
+                    (53.458805084228516, 9.028280973434448),
+                    (49.214558601379395, 10.141990184783936),
+                    (49.09406661987305, 14.470328092575073),
+                    (49.99903678894043, 18.995193243026733),
+                    (53.148255348205566, 22.01805591583252),
+                    (78.61608982086182, 33.28447341918945),
+                    (77.70782947540283, 29.039194583892822),
+                    (75.5536937713623, 25.364770889282227),
+                    (71.55352592468262, 25.063626766204834),
+                    (69.02107238769531, 28.33871364593506),
+                    (69.77151870727539, 32.57174730300903),
+                    (62.65278339385986, 35.05384683609009),
+                    (59.47975158691406, 32.37001895904541),
+                    (55.61235427856445, 33.888020515441895),
+                    (47.91607856750488, 33.94810199737549),
+                    (45.259952545166016, 30.507209300994873),
+                    (42.603816986083984, 27.066309452056885),
+                ],
+            ),
+            (
+                Partie::Nuage,
+                vec![
+                    (113.00000190734863, 113.50000381469727),
+                    (112.04532623291016, 117.5693416595459),
+                    (123.19507598876953, 117.6950740814209),
+                    (120.18107414245605, 114.68107223510742),
+                    (116.84038162231445, 112.11772918701172),
+                    (58.0210542678833, 116.13033294677734),
+                    (54.10573482513428, 117.3944091796875),
+                    (120.75455665588379, 50.76645851135254),
+                    (117.70800590515137, 53.791985511779785),
+                    (116.51957511901855, 57.80807018280029),
+                    (118.95648002624512, 61.102919578552246),
+                    (122.93824195861816, 60.509161949157715),
+                    (123.94362449645996, 90.78798294067383),
+                    (113.99999618530273, 88.99999618530273),
+                    (113.71315956115723, 84.66610908508301),
+                    (112.76695251464844, 80.42880058288574),
+                    (111.09009742736816, 76.42501831054688),
+                    (108.72456550598145, 72.78476238250732),
+                    (109.83677864074707, 68.75612735748291),
+                    (110.44439315795898, 64.45760726928711),
+                    (110.40581703186035, 60.11434555053711),
+                    (109.73776817321777, 55.82351207733154),
+                    (108.36267471313477, 51.70651912689209),
+                    (106.18100166320801, 47.955923080444336),
+                    (103.35564613342285, 44.658756256103516),
+                    (100.08308410644531, 41.80241107940674),
+                    (96.3323974609375, 39.63249444961548),
+                    (92.24224090576172, 38.177034854888916),
+                    (87.95275688171387, 37.522430419921875),
+                    (83.60629081726074, 37.49999523162842),
+                    (79.25947666168213, 37.49999523162842),
+                    (76.98787212371826, 37.04600811004639),
+                    (71.40683650970459, 36.565024852752686),
+                    (69.60442543029785, 37.5),
+                    (65.25760650634766, 37.5),
+                    (53.894410133361816, 37.00000047683716),
+                    (49.54759120941162, 37.00000047683716),
+                    (25.280582904815674, 38.060879707336426),
+                    (21.22426986694336, 39.60620164871216),
+                    (17.538892030715942, 41.89798355102539),
+                    (14.337460994720459, 44.83058452606201),
+                    (11.612982749938965, 48.21296215057373),
+                    (9.496957063674927, 52.00272560119629),
+                    (8.135983943939209, 56.124067306518555),
+                    (7.542250752449036, 60.424790382385254),
+                    (7.666691541671753, 64.76418018341064),
+                    (8.568164110183716, 69.01064395904541),
+                    (10.184322595596313, 73.04038524627686),
+                    (12.438340187072754, 76.75154685974121),
+                    (15.28406023979187, 80.02907752990723),
+                    (18.709079027175903, 82.69415855407715),
+                    (22.571861743927002, 84.67570304870605),
+                    (26.54228448867798, 86.1942195892334),
+                    (27.763566970825195, 90.36225318908691),
+                    (29.58231210708618, 94.30558204650879),
+                    (31.987497806549072, 97.92112350463867),
+                    (34.90213632583618, 101.14107131958008),
+                    (38.219571113586426, 103.9450740814209),
+                    (41.908416748046875, 106.2362289428711),
+                    (45.90794086456299, 107.92756080627441),
+                    (50.11748790740967, 108.99548530578613),
+                    (54.435553550720215, 109.46602821350098),
+                    (58.77907752990723, 109.39299583435059),
+                    (63.076162338256836, 108.76075744628906),
+                    (67.23323822021484, 107.5054931640625),
+                    (70.8443546295166, 107.79409408569336),
+                    (74.20367240905762, 110.54807662963867),
+                    (77.94369220733643, 112.75132179260254),
+                    (82.03729629516602, 114.1944694519043),
+                    (86.3223934173584, 114.89405632019043),
+                    (90.66545486450195, 114.95330810546875),
+                    (94.96676445007324, 114.3610954284668),
+                    (99.09607887268066, 113.02370071411133),
+                    (102.89358139038086, 110.92193603515625),
+                    (106.32158279418945, 108.25553894042969),
+                    (109.30221557617188, 105.09762763977051),
+                    (111.78365707397461, 101.53305053710938),
+                    (113.57067108154297, 97.5804328918457),
+                    (114.36118125915527, 93.3183765411377),
+                ],
+            ),
+            (
+                Partie::Soleil,
+                vec![
+                    (114.46032524108887, 120.96033096313477),
+                    (117.47432708740234, 123.97433280944824),
+                    (120.50930976867676, 126.96603775024414),
+                    (124.45449829101562, 127.64740943908691),
+                    (127.30152130126953, 124.59109306335449),
+                    (126.2052059173584, 120.71285247802734),
+                    (50.999999046325684, 120.50000190734863),
+                    (49.64534282684326, 124.43381309509277),
+                    (52.70007133483887, 127.3039722442627),
+                    (56.85934066772461, 126.63416862487793),
+                    (59.731807708740234, 123.31290245056152),
+                    (60.89861869812012, 119.2201042175293),
+                    (85.50000190734863, 125.0),
+                    (84.49999809265137, 129.1061496734619),
+                    (84.49999809265137, 133.4036636352539),
+                    (84.49999809265137, 137.70118713378906),
+                    (85.70615768432617, 141.60286903381348),
+                    (89.79715347290039, 142.21949577331543),
+                    (92.37391471862793, 139.01512145996094),
+                    (92.50000953674316, 134.72737312316895),
+                    (92.50000953674316, 130.42985916137695),
+                    (92.40643501281738, 126.13809585571289),
+                    (89.28717613220215, 123.59429359436035),
+                    (126.00000381469727, 57.5),
+                    (127.3824405670166, 53.660197257995605),
+                    (124.82221603393555, 50.312747955322266),
+                    (142.5, 88.00000190734863),
+                    (140.50925254821777, 84.52275276184082),
+                    (136.2234592437744, 83.99999618530273),
+                    (131.8553638458252, 83.99999618530273),
+                    (127.48971939086914, 84.04340744018555),
+                    (124.09404754638672, 86.61215782165527),
+                    (127.74640083312988, 92.5),
+                    (132.11450576782227, 92.5),
+                    (136.48260116577148, 92.5),
+                    (140.68976402282715, 91.77922248840332),
+                ],
+            ),
+            (
+                Partie::Eclair,
+                vec![
+                    (39.947683811187744, 23.625409603118896),
+                    (37.29155778884888, 20.18451690673828),
+                    (34.635417461395264, 16.74360990524292),
+                    (31.979291439056396, 13.302717208862305),
+                    (29.32316303253174, 9.861825704574585),
+                    (26.136813163757324, 7.5),
+                    (27.331316471099854, 11.493951082229614),
+                    (28.705904483795166, 15.617715120315552),
+                    (30.080485343933105, 19.74146008491516),
+                    (31.455068588256836, 23.865206241607666),
+                    (32.82965660095215, 27.988967895507812),
+                    (29.191856384277344, 28.499999046325684),
+                    (24.845027923583984, 28.499999046325684),
+                    (22.276594638824463, 30.171728134155273),
+                    (23.931641578674316, 34.191129207611084),
+                ],
+            ),
+        ],
+        AsciiIconType::Neige => vec![
+            (
+                Partie::Neige,
+                vec![
+                    (46.500000953674316, 25.499999523162842),
+                    (47.474374771118164, 21.395795345306396),
+                    (44.566779136657715, 18.6672043800354),
+                    (40.53959369659424, 19.460960626602173),
+                    (39.603095054626465, 23.542001247406006),
+                    (42.45866298675537, 26.340162754058838),
+                    (62.5, 34.50000047683716),
+                    (63.45022678375244, 30.396487712860107),
+                    (60.54156303405762, 27.659928798675537),
+                    (56.520328521728516, 28.479831218719482),
+                    (55.54603099822998, 32.57512092590332),
+                    (58.43092441558838, 35.33210277557373),
+                    (78.49999904632568, 25.499999523162842),
+                    (79.47398662567139, 21.391117572784424),
+                    (76.55775547027588, 18.66461753845215),
+                    (72.52951622009277, 19.47080135345459),
+                    (71.59152507781982, 23.56261968612671),
+                    (74.45415496826172, 26.33883237838745),
+                    (62.5, 16.00000023841858),
+                    (63.47397804260254, 11.890960931777954),
+                    (60.55746555328369, 9.164479374885559),
+                    (56.529178619384766, 9.971134662628174),
+                    (55.54499626159668, 14.06691312789917),
+                    (58.42695236206055, 16.830965280532837),
+                ],
+            ),
+            (
+                Partie::Soleil,
+                vec![
+                    (141.99999809265137, 86.99999809265137),
+                    (140.1032257080078, 83.56507301330566),
+                    (135.929594039917, 82.99999237060547),
+                    (131.6645622253418, 82.99999237060547),
+                    (127.40344047546387, 83.05932998657227),
+                    (124.10223960876465, 85.59920310974121),
+                    (124.2253589630127, 89.5860481262207),
+                    (127.87405014038086, 91.49737358093262),
+                    (132.1390438079834, 91.49999618530273),
+                    (136.4040756225586, 91.49999618530273),
+                    (140.5453109741211, 90.88921546936035),
+                    (114.37074661254883, 118.96865844726562),
+                    (117.25990295410156, 122.06418037414551),
+                    (120.1954460144043, 125.09757041931152),
+                    (124.25751686096191, 125.92630386352539),
+                    (127.26558685302734, 123.1857681274414),
+                    (126.29246711730957, 119.30839538574219),
+                    (50.999999046325684, 118.99999618530273),
+                    (50.03746509552002, 123.1870174407959),
+                    (53.18305015563965, 125.79937934875488),
+                    (57.33583450317383, 125.26982307434082),
+                    (60.373191833496094, 122.1268081665039),
+                    (61.34378433227539, 118.03189277648926),
+                    (126.00000381469727, 56.500000953674316),
+                    (127.47940063476562, 52.678232192993164),
+                    (125.05654335021973, 49.40385818481445),
+                    (85.50000190734863, 123.50000381469727),
+                    (84.49999809265137, 126.91903114318848),
+                    (84.49999809265137, 130.52942276000977),
+                    (84.49999809265137, 134.13981437683105),
+                    (84.7470760345459, 137.7175521850586),
+                    (87.09505081176758, 140.39512634277344),
+                    (90.52124977111816, 140.61420440673828),
+                    (92.72207260131836, 137.90579795837402),
+                    (93.00000190734863, 134.32979583740234),
+                    (93.00000190734863, 130.7193946838379),
+                    (93.00000190734863, 127.1090030670166),
+                    (92.18238830566406, 123.69481086730957),
+                    (88.84787559509277, 122.5001335144043),
+                ],
+            ),
+            (
+                Partie::Nuage,
+                vec![
+                    (115.50000190734863, 87.5),
+                    (115.21321296691895, 83.38025093078613),
+                    (114.2923641204834, 79.35580730438232),
+                    (112.68630027770996, 75.55347442626953),
+                    (110.42322158813477, 72.10137367248535),
+                    (110.51998138427734, 68.50734233856201),
+                    (111.34061813354492, 64.46411609649658),
+                    (111.49111747741699, 60.33613204956055),
+                    (111.0246753692627, 56.2346887588501),
+                    (109.83033180236816, 52.2836971282959),
+                    (107.96671867370605, 48.59972953796387),
+                    (105.5655574798584, 45.2394962310791),
+                    (102.65694618225098, 42.314252853393555),
+                    (99.28879737854004, 39.926090240478516),
+                    (95.57753562927246, 38.11753749847412),
+                    (91.61765098571777, 36.949708461761475),
+                    (87.51859664916992, 36.499996185302734),
+                    (83.38533401489258, 36.499996185302734),
+                    (79.25207138061523, 36.499996185302734),
+                    (75.11881351470947, 36.499998569488525),
+                    (70.98555088043213, 36.499998569488525),
+                    (66.85228824615479, 36.499998569488525),
+                    (62.719035148620605, 36.499998569488525),
+                    (58.58577251434326, 36.499998569488525),
+                    (54.45250988006592, 36.499996185302734),
+                    (50.319247245788574, 36.499996185302734),
+                    (46.18598461151123, 36.499996185302734),
+                    (42.05272197723389, 36.499996185302734),
+                    (37.91945934295654, 36.499996185302734),
+                    (33.78620624542236, 36.499996185302734),
+                    (29.654231071472168, 36.559226512908936),
+                    (25.57558536529541, 37.191617488861084),
+                    (21.716361045837402, 38.64690065383911),
+                    (18.269490003585815, 40.916175842285156),
+                    (15.16369342803955, 43.63943576812744),
+                    (12.46090054512024, 46.76125526428223),
+                    (10.31934380531311, 50.289649963378906),
+                    (8.872714042663574, 54.15507793426514),
+                    (8.133713603019714, 58.216800689697266),
+                    (8.034221529960632, 62.34515190124512),
+                    (8.59052062034607, 66.43571853637695),
+                    (9.851885437965393, 70.36612510681152),
+                    (11.763291358947754, 74.02576446533203),
+                    (14.198023080825806, 77.36175537109375),
+                    (17.09855556488037, 80.30020713806152),
+                    (20.441815853118896, 82.72212982177734),
+                    (24.143381118774414, 84.55060005187988),
+                    (27.27229595184326, 86.58782958984375),
+                    (28.627662658691406, 90.48884391784668),
+                    (30.532608032226562, 94.15277481079102),
+                    (32.94403076171875, 97.50548362731934),
+                    (35.78474521636963, 100.50393104553223),
+                    (38.92754316329956, 103.18667411804199),
+                    (42.40915298461914, 105.4046630859375),
+                    (46.2134313583374, 107.00894355773926),
+                    (50.219478607177734, 108.01156044006348),
+                    (54.32542324066162, 108.46104621887207),
+                    (58.45414638519287, 108.37997436523438),
+                    (62.52033233642578, 107.66104698181152),
+                    (66.44536972045898, 106.3750171661377),
+                    (70.05924224853516, 105.54716110229492),
+                    (73.21244239807129, 108.21518898010254),
+                    (76.69713020324707, 110.43120384216309),
+                    (80.4776668548584, 112.09109306335449),
+                    (84.47615623474121, 113.11954498291016),
+                    (88.58795166015625, 113.4968376159668),
+                    (92.71085739135742, 113.26151847839355),
+                    (96.74606323242188, 112.38899230957031),
+                    (100.56471824645996, 110.82272529602051),
+                    (104.05086517333984, 108.6093807220459),
+                    (107.19371795654297, 105.93048095703125),
+                    (109.90934371948242, 102.81939506530762),
+                    (112.1654224395752, 99.36031341552734),
+                    (113.81537437438965, 95.5760669708252),
+                    (114.87814903259277, 91.58485412597656),
+                    (113.00000190734863, 111.49999618530273),
+                    (112.04188346862793, 115.54141998291016),
+                    (123.30646514892578, 116.30645751953125),
+                    (120.31235694885254, 113.31234931945801),
+                    (117.04427719116211, 110.68181991577148),
+                    (58.34214687347412, 115.08955955505371),
+                    (54.325194358825684, 116.14983558654785),
+                    (120.97066879272461, 49.61935043334961),
+                    (117.91254997253418, 52.587432861328125),
+                    (116.50376319885254, 56.46660327911377),
+                    (118.82583618164062, 59.61972713470459),
+                    (122.93757438659668, 59.463562965393066),
+                ],
+            ),
+        ],
+        AsciiIconType::Lune => vec![(Partie::Lune, croissant((80.0, 80.0), 45.0))],
+        AsciiIconType::LuneNuageux => vec![
+            (Partie::Lune, lune_nuageuse_lune()),
+            (Partie::Nuage, lune_nuageuse_nuage()),
+        ],
+        AsciiIconType::PluieVerglacante => {
+            let mut parties = get_icon_parties(AsciiIconType::Pluie);
+            parties.push((Partie::Glace, glace()));
+            parties
         }
-        AsciiIconType::Pluie => {
-            vec![
-                (75.99999904632568, 32.00000047683716),
-                (76.33109092712402, 27.98654317855835),
-                (75.19199848175049, 23.991069793701172),
-                (71.47638320922852, 22.50000238418579),
-                (68.26160430908203, 24.533488750457764),
-                (68.11460494995117, 28.68767499923706),
-                (69.43090438842773, 32.59615421295166),
-                (73.16407680511475, 34.203500747680664),
-                (56.500000953674316, 17.5),
-                (56.8422269821167, 13.053334951400757),
-                (55.21637439727783, 8.868667483329773),
-                (50.80210208892822, 8.082695007324219),
-                (48.00023555755615, 11.322021484375),
-                (48.67741107940674, 15.852278470993042),
-                (52.114014625549316, 18.651270866394043),
-                (58.499999046325684, 29.500000476837158),
-                (59.45066452026367, 25.39973258972168),
-                (56.547861099243164, 22.661714553833008),
-                (52.5273323059082, 23.472940921783447),
-                (51.54438495635986, 27.562124729156494),
-                (54.41521167755127, 30.32749891281128),
-                (43.499999046325684, 31.500000953674316),
-                (43.812899589538574, 27.377395629882812),
-                (42.54326343536377, 23.40961456298828),
-                (38.780739307403564, 22.37276792526245),
-                (35.570409297943115, 24.973294734954834),
-                (35.17235517501831, 29.034125804901123),
-                (36.669418811798096, 32.87452459335327),
-                (40.49798011779785, 34.00100231170654),
-                (113.50000381469727, 113.00000190734863),
-                (112.51462936401367, 116.94222450256348),
-                (114.74931716918945, 120.33855438232422),
-                (117.60252952575684, 123.39556694030762),
-                (120.45574188232422, 126.45257949829102),
-                (124.42456245422363, 127.47068405151367),
-                (127.25107192993164, 124.82318878173828),
-                (126.79278373718262, 120.82717895507812),
-                (123.853759765625, 117.85375595092773),
-                (120.89689254760742, 114.89688873291016),
-                (117.5494384765625, 112.49552726745605),
-                (115.50000190734863, 88.50000381469727),
-                (115.14161109924316, 84.19084548950195),
-                (114.04787063598633, 80.00785827636719),
-                (112.25456237792969, 76.07311248779297),
-                (109.86652374267578, 72.46684551239014),
-                (110.72639465332031, 68.47504615783691),
-                (111.4248275756836, 64.20907497406006),
-                (111.4093017578125, 59.88428592681885),
-                (110.63424110412598, 55.63164710998535),
-                (109.07374382019043, 51.60017967224121),
-                (106.8382453918457, 47.898311614990234),
-                (104.09086227416992, 44.55667972564697),
-                (100.84141731262207, 41.70581340789795),
-                (97.10504531860352, 39.533162117004395),
-                (93.01566123962402, 38.133482933044434),
-                (88.73486518859863, 37.53044366836548),
-                (84.40621376037598, 37.50000238418579),
-                (80.07705688476562, 37.50000238418579),
-                (75.74789047241211, 37.5),
-                (71.4187240600586, 37.5),
-                (67.08956718444824, 37.5),
-                (62.76041030883789, 37.5),
-                (60.17472743988037, 34.338717460632324),
-                (56.018733978271484, 34.288737773895264),
-                (53.86636257171631, 37.50000476837158),
-                (49.53720569610596, 37.50000476837158),
-                (45.20803928375244, 37.50000476837158),
-                (40.87888240814209, 37.50000238418579),
-                (36.549715995788574, 37.50000238418579),
-                (32.22055912017822, 37.50000238418579),
-                (28.400416374206543, 38.20399045944214),
-                (24.194562435150146, 39.19924736022949),
-                (20.26515245437622, 41.00022792816162),
-                (16.72030210494995, 43.47667217254639),
-                (13.589555025100708, 46.461172103881836),
-                (11.004495620727539, 49.926419258117676),
-                (9.092050790786743, 53.803462982177734),
-                (7.9169005155563354, 57.963881492614746),
-                (7.501073479652405, 62.267441749572754),
-                (7.826253175735474, 66.57899856567383),
-                (8.919008374214172, 70.76152324676514),
-                (10.7522714138031, 74.67721462249756),
-                (13.210043907165527, 78.23387145996094),
-                (16.343777179718018, 81.21466636657715),
-                (19.915350675582886, 83.65221977233887),
-                (23.835346698760986, 85.47924995422363),
-                (27.224762439727783, 87.47711181640625),
-                (28.54766845703125, 91.59538269042969),
-                (30.466363430023193, 95.47134399414062),
-                (32.95754909515381, 99.00675773620605),
-                (35.936219692230225, 102.14354515075684),
-                (39.296414852142334, 104.86845016479492),
-                (43.00402641296387, 107.09536552429199),
-                (47.01577186584473, 108.70966911315918),
-                (51.23405456542969, 109.66361999511719),
-                (55.54710865020752, 109.99711990356445),
-                (59.85799789428711, 109.67020034790039),
-                (64.06736850738525, 108.67344856262207),
-                (68.11790466308594, 107.15288162231445),
-                (71.55674934387207, 108.39954376220703),
-                (75.03910541534424, 110.96526145935059),
-                (78.86952877044678, 112.97238349914551),
-                (82.9794692993164, 114.3156623840332),
-                (87.25752830505371, 114.94510650634766),
-                (91.58291816711426, 114.90111351013184),
-                (95.84646224975586, 114.18661117553711),
-                (99.87343788146973, 112.61589050292969),
-                (103.69101524353027, 110.58268547058105),
-                (107.08468437194824, 107.90594100952148),
-                (109.87345695495605, 104.60591316223145),
-                (112.05399513244629, 100.86911201477051),
-                (113.90748023986816, 96.95917129516602),
-                (115.10156631469727, 92.80449867248535),
-                (142.5, 88.99999618530273),
-                (140.62116622924805, 85.57324409484863),
-                (136.46902084350586, 84.99999046325684),
-                (132.22370147705078, 84.99999046325684),
-                (127.98095703125, 85.0448989868164),
-                (124.65580940246582, 87.51646041870117),
-                (124.6589183807373, 91.48819923400879),
-                (128.2363510131836, 93.48867416381836),
-                (132.4813461303711, 93.4999942779541),
-                (136.72666549682617, 93.4999942779541),
-                (140.80645561218262, 92.81828880310059),
-                (126.49999618530273, 58.00000190734863),
-                (127.44193077087402, 53.8386344909668),
-                (124.4288444519043, 51.12898826599121),
-                (120.39626121520996, 52.10372447967529),
-                (117.31950759887695, 55.186285972595215),
-                (116.21818542480469, 59.14417266845703),
-                (119.40962791442871, 61.82581901550293),
-                (123.44677925109863, 61.09932899475098),
-                (51.500000953674316, 120.50000190734863),
-                (50.09661674499512, 124.56168174743652),
-                (52.64862060546875, 128.04621696472168),
-                (56.97248935699463, 127.63043403625488),
-                (59.92148399353027, 124.17494773864746),
-                (61.463356018066406, 120.03108024597168),
-                (58.76730918884277, 116.52064323425293),
-                (54.659061431884766, 117.23607063293457),
-                (85.50000190734863, 125.0),
-                (84.49999809265137, 129.49875831604004),
-                (84.49999809265137, 134.18889045715332),
-                (84.50202941894531, 138.87901306152344),
-                (87.14101791381836, 142.23867416381836),
-                (91.52206420898438, 141.47777557373047),
-                (93.00000190734863, 137.22195625305176),
-                (93.00000190734863, 132.53183364868164),
-                (92.98943519592285, 127.84185409545898),
-                (90.12279510498047, 124.65099334716797),
-            ]
+        AsciiIconType::OrageGrele => {
+            let mut parties = get_icon_parties(AsciiIconType::Orages);
+            parties.push((Partie::Grele, grelons()));
+            parties
         }
-        AsciiIconType::Averses => {
-            vec![
-                (113.99999618530273, 113.50000381469727),
-                (112.59758949279785, 117.26492881774902),
-                (115.09340286254883, 120.59341430664062),
-                (118.074951171875, 123.5749626159668),
-                (121.07150077819824, 126.53571128845215),
-                (125.10307312011719, 127.45022773742676),
-                (127.84276008605957, 124.64884757995605),
-                (126.76837921142578, 120.78160285949707),
-                (123.79354476928711, 117.79354095458984),
-                (120.81199645996094, 114.81199264526367),
-                (117.56057739257812, 112.18749046325684),
-                (50.50000190734863, 120.50000190734863),
-                (49.80435848236084, 124.69029426574707),
-                (53.477210998535156, 126.85754776000977),
-                (57.593374252319336, 125.90662956237793),
-                (60.70180416107178, 122.74567604064941),
-                (61.13163471221924, 118.51333618164062),
-                (57.649264335632324, 116.06520652770996),
-                (53.63657474517822, 117.3634147644043),
-                (85.50000190734863, 124.49999809265137),
-                (84.49999809265137, 128.55730056762695),
-                (84.49999809265137, 132.80597686767578),
-                (84.49999809265137, 137.0546531677246),
-                (86.19562149047852, 140.66173553466797),
-                (90.28562545776367, 141.4553165435791),
-                (92.92069435119629, 138.49206924438477),
-                (93.00000190734863, 134.2477035522461),
-                (93.00000190734863, 129.99903678894043),
-                (92.86005973815918, 125.76251983642578),
-                (89.48431015014648, 123.53705406188965),
-                (126.49999618530273, 56.99999809265137),
-                (127.97066688537598, 53.07536602020264),
-                (125.14513969421387, 50.1908016204834),
-                (120.99075317382812, 51.00792407989502),
-                (117.67366409301758, 53.851141929626465),
-                (116.52193069458008, 57.924137115478516),
-                (119.29622650146484, 60.79075336456299),
-                (123.40939521789551, 60.087876319885254),
-                (141.99999809265137, 88.00000190734863),
-                (140.05006790161133, 84.54096794128418),
-                (135.8128547668457, 83.99999618530273),
-                (131.48944854736328, 83.99999618530273),
-                (127.17628479003906, 84.11308288574219),
-                (123.95360946655273, 86.85030937194824),
-                (124.43404197692871, 90.86722373962402),
-                (128.03056716918945, 92.99999237060547),
-                (132.35397338867188, 92.99999237060547),
-                (136.6773796081543, 92.99999237060547),
-                (140.6785488128662, 91.80417060852051),
-                (114.49999809265137, 95.50000190734863),
-                (114.98929023742676, 91.3814640045166),
-                (114.73541259765625, 87.24264144897461),
-                (113.76080513000488, 83.21069717407227),
-                (112.15983390808105, 79.38507080078125),
-                (110.0791072845459, 75.89341163635254),
-                (112.13248252868652, 72.29321002960205),
-                (113.44464302062988, 68.3600902557373),
-                (113.98686408996582, 64.24967765808105),
-                (113.82326126098633, 60.10375499725342),
-                (113.12410354614258, 56.01323127746582),
-                (111.66152954101562, 52.138309478759766),
-                (109.39362525939941, 48.667540550231934),
-                (106.61829948425293, 45.58151721954346),
-                (103.51152420043945, 42.83581256866455),
-                (99.9436092376709, 40.72336673736572),
-                (96.05109214782715, 39.2929744720459),
-                (91.97154998779297, 38.54361295700073),
-                (87.82112121582031, 38.500003814697266),
-                (83.66867065429688, 38.500003814697266),
-                (81.3176441192627, 36.11177921295166),
-                (80.17687797546387, 32.11910009384155),
-                (79.03611660003662, 28.126418590545654),
-                (76.65315628051758, 24.80994939804077),
-                (72.61176109313965, 24.84755277633667),
-                (70.50090312957764, 28.22399139404297),
-                (71.15881443023682, 32.29997396469116),
-                (72.10988521575928, 36.34203910827637),
-                (70.05078792572021, 38.000006675720215),
-                (65.89833736419678, 38.000006675720215),
-                (63.78399848937988, 35.34059524536133),
-                (62.70447254180908, 31.330924034118652),
-                (61.6249418258667, 27.321243286132812),
-                (60.5454158782959, 23.311572074890137),
-                (59.4658899307251, 19.30190086364746),
-                (58.3863639831543, 15.29222846031189),
-                (57.16548442840576, 11.336570978164673),
-                (54.24787521362305, 8.607701659202576),
-                (50.23768424987793, 9.093294739723206),
-                (49.000000953674316, 12.864872217178345),
-                (49.93767261505127, 16.890041828155518),
-                (51.044654846191406, 20.89221954345703),
-                (52.151641845703125, 24.894397258758545),
-                (53.258628845214844, 28.89657497406006),
-                (54.36561584472656, 32.89875268936157),
-                (55.4725980758667, 36.900930404663086),
-                (51.45033836364746, 36.99999809265137),
-                (47.29788780212402, 36.99999809265137),
-                (45.94107151031494, 33.29374551773071),
-                (44.80030536651611, 29.301066398620605),
-                (43.392372131347656, 25.42062759399414),
-                (39.94706630706787, 23.499999046325684),
-                (36.18567943572998, 24.683492183685303),
-                (35.011751651763916, 28.55581760406494),
-                (35.86719989776611, 32.619194984436035),
-                (36.72264814376831, 36.68257713317871),
-                (34.19384479522705, 37.99999952316284),
-                (30.04223108291626, 38.042452335357666),
-                (25.939061641693115, 38.64419937133789),
-                (22.013554573059082, 39.97950077056885),
-                (18.408700227737427, 42.027997970581055),
-                (15.24503231048584, 44.70877647399902),
-                (12.474521398544312, 47.79839515686035),
-                (10.231384038925171, 51.28652572631836),
-                (8.633005023002625, 55.113229751586914),
-                (7.724189758300781, 59.15970325469971),
-                (7.51211404800415, 63.30197334289551),
-                (7.980884909629822, 67.42285251617432),
-                (9.160770177841187, 71.39841556549072),
-                (11.008689403533936, 75.11170864105225),
-                (13.40104341506958, 78.50152492523193),
-                (16.31603240966797, 81.45038604736328),
-                (19.730329513549805, 83.80331039428711),
-                (23.497984409332275, 85.53903579711914),
-                (26.723949909210205, 87.47383117675781),
-                (27.980871200561523, 91.4281177520752),
-                (29.785747528076172, 95.16360282897949),
-                (32.1220064163208, 98.59195709228516),
-                (34.91875886917114, 101.65708541870117),
-                (38.0817437171936, 104.34338569641113),
-                (41.584811210632324, 106.56598091125488),
-                (45.37863254547119, 108.24465751647949),
-                (49.37644958496094, 109.354248046875),
-                (53.488402366638184, 109.91164207458496),
-                (57.637572288513184, 109.94647979736328),
-                (61.74237251281738, 109.34797286987305),
-                (65.71675300598145, 108.1559944152832),
-                (69.51463222503662, 106.51458740234375),
-                (72.58732318878174, 109.30490493774414),
-                (75.98941802978516, 111.67900085449219),
-                (79.76004600524902, 113.39865684509277),
-                (83.7626838684082, 114.49019432067871),
-                (87.88249969482422, 114.97677803039551),
-                (92.03445434570312, 115.03100395202637),
-                (96.17874145507812, 114.81964111328125),
-                (100.22652626037598, 113.92439842224121),
-                (103.99023056030273, 112.1945571899414),
-                (107.46010780334473, 109.91982460021973),
-                (110.44064521789551, 107.04020500183105),
-                (112.68258094787598, 103.5619068145752),
-                (113.92899513244629, 99.6053695678711),
-            ]
+        AsciiIconType::VentDeSable => {
+            let mut parties = get_icon_parties(AsciiIconType::Brume);
+            parties.push((Partie::Sable, grains_de_sable()));
+            parties
         }
-        AsciiIconType::Orages => {
-            vec![
-                (57.5, 20.999999046325684),
-                (57.722816467285156, 16.576050519943237),
-                (56.48045063018799, 12.141764163970947),
-                (53.458805084228516, 9.028280973434448),
-                (49.214558601379395, 10.141990184783936),
-                (49.09406661987305, 14.470328092575073),
-                (49.99903678894043, 18.995193243026733),
-                (53.148255348205566, 22.01805591583252),
-                (113.00000190734863, 113.50000381469727),
-                (112.04532623291016, 117.5693416595459),
-                (114.46032524108887, 120.96033096313477),
-                (117.47432708740234, 123.97433280944824),
-                (120.50930976867676, 126.96603775024414),
-                (124.45449829101562, 127.64740943908691),
-                (127.30152130126953, 124.59109306335449),
-                (126.2052059173584, 120.71285247802734),
-                (123.19507598876953, 117.6950740814209),
-                (120.18107414245605, 114.68107223510742),
-                (116.84038162231445, 112.11772918701172),
-                (50.999999046325684, 120.50000190734863),
-                (49.64534282684326, 124.43381309509277),
-                (52.70007133483887, 127.3039722442627),
-                (56.85934066772461, 126.63416862487793),
-                (59.731807708740234, 123.31290245056152),
-                (60.89861869812012, 119.2201042175293),
-                (58.0210542678833, 116.13033294677734),
-                (54.10573482513428, 117.3944091796875),
-                (85.50000190734863, 125.0),
-                (84.49999809265137, 129.1061496734619),
-                (84.49999809265137, 133.4036636352539),
-                (84.49999809265137, 137.70118713378906),
-                (85.70615768432617, 141.60286903381348),
-                (89.79715347290039, 142.21949577331543),
-                (92.37391471862793, 139.01512145996094),
-                (92.50000953674316, 134.72737312316895),
-                (92.50000953674316, 130.42985916137695),
-                (92.40643501281738, 126.13809585571289),
-                (89.28717613220215, 123.59429359436035),
-                (126.00000381469727, 57.5),
-                (127.3824405670166, 53.660197257995605),
-                (124.82221603393555, 50.312747955322266),
-                (120.75455665588379, 50.76645851135254),
-                (117.70800590515137, 53.791985511779785),
-                (116.51957511901855, 57.80807018280029),
-                (118.95648002624512, 61.102919578552246),
-                (122.93824195861816, 60.509161949157715),
-                (142.5, 88.00000190734863),
-                (140.50925254821777, 84.52275276184082),
-                (136.2234592437744, 83.99999618530273),
-                (131.8553638458252, 83.99999618530273),
-                (127.48971939086914, 84.04340744018555),
-                (124.09404754638672, 86.61215782165527),
-                (123.94362449645996, 90.78798294067383),
-                (127.74640083312988, 92.5),
-                (132.11450576782227, 92.5),
-                (136.48260116577148, 92.5),
-                (140.68976402282715, 91.77922248840332),
-                (113.99999618530273, 88.99999618530273),
-                (113.71315956115723, 84.66610908508301),
-                (112.76695251464844, 80.42880058288574),
-                (111.09009742736816, 76.42501831054688),
-                (108.72456550598145, 72.78476238250732),
-                (109.83677864074707, 68.75612735748291),
-                (110.44439315795898, 64.45760726928711),
-                (110.40581703186035, 60.11434555053711),
-                (109.73776817321777, 55.82351207733154),
-                (108.36267471313477, 51.70651912689209),
-                (106.18100166320801, 47.955923080444336),
-                (103.35564613342285, 44.658756256103516),
-                (100.08308410644531, 41.80241107940674),
-                (96.3323974609375, 39.63249444961548),
-                (92.24224090576172, 38.177034854888916),
-                (87.95275688171387, 37.522430419921875),
-                (83.60629081726074, 37.49999523162842),
-                (79.25947666168213, 37.49999523162842),
-                (76.98787212371826, 37.04600811004639),
-                (78.61608982086182, 33.28447341918945),
-                (77.70782947540283, 29.039194583892822),
-                (75.5536937713623, 25.364770889282227),
-                (71.55352592468262, 25.063626766204834),
-                (69.02107238769531, 28.33871364593506),
-                (69.77151870727539, 32.57174730300903),
-                (71.40683650970459, 36.565024852752686),
-                (69.60442543029785, 37.5),
-                (65.25760650634766, 37.5),
-                (62.65278339385986, 35.05384683609009),
-                (59.47975158691406, 32.37001895904541),
-                (55.61235427856445, 33.888020515441895),
-                (53.894410133361816, 37.00000047683716),
-                (49.54759120941162, 37.00000047683716),
-                (47.91607856750488, 33.94810199737549),
-                (45.259952545166016, 30.507209300994873),
-                (42.603816986083984, 27.066309452056885),
-                (39.947683811187744, 23.625409603118896),
-                (37.29155778884888, 20.18451690673828),
-                (34.635417461395264, 16.74360990524292),
-                (31.979291439056396, 13.302717208862305),
-                (29.32316303253174, 9.861825704574585),
-                (26.136813163757324, 7.5),
-                (27.331316471099854, 11.493951082229614),
-                (28.705904483795166, 15.617715120315552),
-                (30.080485343933105, 19.74146008491516),
-                (31.455068588256836, 23.865206241607666),
-                (32.82965660095215, 27.988967895507812),
-                (29.191856384277344, 28.499999046325684),
-                (24.845027923583984, 28.499999046325684),
-                (22.276594638824463, 30.171728134155273),
-                (23.931641578674316, 34.191129207611084),
-                (25.280582904815674, 38.060879707336426),
-                (21.22426986694336, 39.60620164871216),
-                (17.538892030715942, 41.89798355102539),
-                (14.337460994720459, 44.83058452606201),
-                (11.612982749938965, 48.21296215057373),
-                (9.496957063674927, 52.00272560119629),
-                (8.135983943939209, 56.124067306518555),
-                (7.542250752449036, 60.424790382385254),
-                (7.666691541671753, 64.76418018341064),
-                (8.568164110183716, 69.01064395904541),
-                (10.184322595596313, 73.04038524627686),
-                (12.438340187072754, 76.75154685974121),
-                (15.28406023979187, 80.02907752990723),
-                (18.709079027175903, 82.69415855407715),
-                (22.571861743927002, 84.67570304870605),
-                (26.54228448867798, 86.1942195892334),
-                (27.763566970825195, 90.36225318908691),
-                (29.58231210708618, 94.30558204650879),
-                (31.987497806549072, 97.92112350463867),
-                (34.90213632583618, 101.14107131958008),
-                (38.219571113586426, 103.9450740814209),
-                (41.908416748046875, 106.2362289428711),
-                (45.90794086456299, 107.92756080627441),
-                (50.11748790740967, 108.99548530578613),
-                (54.435553550720215, 109.46602821350098),
-                (58.77907752990723, 109.39299583435059),
-                (63.076162338256836, 108.76075744628906),
-                (67.23323822021484, 107.5054931640625),
-                (70.8443546295166, 107.79409408569336),
-                (74.20367240905762, 110.54807662963867),
-                (77.94369220733643, 112.75132179260254),
-                (82.03729629516602, 114.1944694519043),
-                (86.3223934173584, 114.89405632019043),
-                (90.66545486450195, 114.95330810546875),
-                (94.96676445007324, 114.3610954284668),
-                (99.09607887268066, 113.02370071411133),
-                (102.89358139038086, 110.92193603515625),
-                (106.32158279418945, 108.25553894042969),
-                (109.30221557617188, 105.09762763977051),
-                (111.78365707397461, 101.53305053710938),
-                (113.57067108154297, 97.5804328918457),
-                (114.36118125915527, 93.3183765411377),
-            ]
+        AsciiIconType::Grele => vec![
+            (
+                Partie::Nuage,
+                vec![
+                    (113.50000381469727, 113.00000190734863),
+                    (112.11427688598633, 116.82796478271484),
+                    (123.87687683105469, 117.68949508666992),
+                    (120.95535278320312, 114.55928802490234),
+                    (117.52745628356934, 112.15585708618164),
+                    (57.8605842590332, 116.12345695495605),
+                    (53.6867094039917, 117.31329917907715),
+                    (121.07674598693848, 50.926856994628906),
+                    (117.88983345031738, 53.665852546691895),
+                    (116.50458335876465, 57.4975061416626),
+                    (118.79585266113281, 60.607595443725586),
+                    (122.74728775024414, 60.15826225280762),
+                    (123.82363319396973, 90.05965232849121),
+                    (115.50000190734863, 90.0),
+                    (115.14244079589844, 85.69570541381836),
+                    (114.05116081237793, 81.51714324951172),
+                    (112.26179122924805, 77.58599758148193),
+                    (109.87853050231934, 73.98247241973877),
+                    (110.77169418334961, 70.02141952514648),
+                    (111.42558097839355, 65.75276374816895),
+                    (111.42455101013184, 61.43205642700195),
+                    (110.73822021484375, 57.168240547180176),
+                    (109.29325103759766, 53.099122047424316),
+                    (107.15070724487305, 49.34884071350098),
+                    (104.46639060974121, 45.96277713775635),
+                    (101.28988265991211, 43.03791522979736),
+                    (97.61421203613281, 40.77332019805908),
+                    (93.56654167175293, 39.27142858505249),
+                    (89.30662155151367, 38.56539011001587),
+                    (84.98396873474121, 38.499999046325684),
+                    (80.65973281860352, 38.499999046325684),
+                    (70.86928367614746, 37.57519245147705),
+                    (67.12021827697754, 37.99999713897705),
+                    (62.94666290283203, 37.80308246612549),
+                    (53.55398654937744, 36.500000953674316),
+                    (49.22976016998291, 36.500000953674316),
+                    (25.26947021484375, 36.34683132171631),
+                    (22.161095142364502, 38.42353582382202),
+                    (18.439334630966187, 40.617499351501465),
+                    (15.078015327453613, 43.33204746246338),
+                    (12.104973793029785, 46.468162536621094),
+                    (9.80242669582367, 50.11669635772705),
+                    (8.332213163375854, 54.17590618133545),
+                    (7.611165642738342, 58.434648513793945),
+                    (7.558487057685852, 62.754364013671875),
+                    (8.230270743370056, 67.0201063156128),
+                    (9.671045541763306, 71.09079360961914),
+                    (11.80001974105835, 74.84906673431396),
+                    (14.486924409866333, 78.23102951049805),
+                    (17.752246856689453, 81.05515480041504),
+                    (21.49681806564331, 83.20592880249023),
+                    (25.541558265686035, 84.72517967224121),
+                    (27.362256050109863, 88.21187973022461),
+                    (29.001412391662598, 92.20901489257812),
+                    (31.23133420944214, 95.90888023376465),
+                    (33.99304389953613, 99.23133850097656),
+                    (37.181994915008545, 102.14755058288574),
+                    (40.74140548706055, 104.59579467773438),
+                    (44.63468551635742, 106.46754264831543),
+                    (48.76811504364014, 107.7239990234375),
+                    (53.03939342498779, 108.37663650512695),
+                    (57.359862327575684, 108.47484588623047),
+                    (61.65879726409912, 108.0373764038086),
+                    (65.85286617279053, 107.00116157531738),
+                    (69.76778984069824, 105.76499938964844),
+                    (73.04036140441895, 108.58675956726074),
+                    (76.67625427246094, 110.91992378234863),
+                    (80.6358814239502, 112.64530181884766),
+                    (84.8296070098877, 113.67782592773438),
+                    (89.13711547851562, 113.99965286254883),
+                    (93.44318389892578, 113.65428924560547),
+                    (97.63041496276855, 112.59771347045898),
+                    (101.54488563537598, 110.77668190002441),
+                    (105.18246650695801, 108.45186233520508),
+                    (108.27644348144531, 105.43985366821289),
+                    (110.86856842041016, 101.98230743408203),
+                    (113.08605194091797, 98.27569961547852),
+                    (114.57842826843262, 94.2226505279541),
+                ],
+            ),
+            (
+                Partie::Soleil,
+                vec![
+                    (114.68562126159668, 120.18562316894531),
+                    (117.7132797241211, 123.21328163146973),
+                    (120.74594497680664, 126.23579025268555),
+                    (124.67817306518555, 127.4675464630127),
+                    (127.74320602416992, 124.7410774230957),
+                    (126.8006420135498, 120.81748962402344),
+                    (85.50000190734863, 124.49999809265137),
+                    (84.0000057220459, 128.39564323425293),
+                    (84.0000057220459, 132.7346897125244),
+                    (84.0000057220459, 137.07372665405273),
+                    (85.47842979431152, 140.9782600402832),
+                    (89.61668014526367, 141.9796085357666),
+                    (92.32951164245605, 139.07808303833008),
+                    (92.50000953674316, 134.75622177124023),
+                    (92.50000953674316, 130.41717529296875),
+                    (92.39507675170898, 126.0851001739502),
+                    (89.19363021850586, 123.57404708862305),
+                    (50.50000190734863, 120.50000190734863),
+                    (49.772095680236816, 124.77766990661621),
+                    (53.16892147064209, 127.41198539733887),
+                    (57.30479717254639, 126.23875617980957),
+                    (60.633039474487305, 123.23296546936035),
+                    (61.25053405761719, 118.91434669494629),
+                    (126.00000381469727, 57.5),
+                    (127.40594863891602, 53.74880790710449),
+                    (124.98268127441406, 50.40140628814697),
+                    (142.5, 88.00000190734863),
+                    (140.35143852233887, 84.42158699035645),
+                    (136.26089096069336, 83.50000381469727),
+                    (131.9479465484619, 83.50000381469727),
+                    (127.63588905334473, 83.52212905883789),
+                    (124.20548439025879, 85.9426498413086),
+                    (127.4332332611084, 91.9992733001709),
+                    (131.74617767333984, 92.00000762939453),
+                    (136.05913162231445, 92.00000762939453),
+                    (140.2063751220703, 91.26579284667969),
+                ],
+            ),
+            (
+                Partie::Grele,
+                vec![
+                    (78.65303993225098, 35.450944900512695),
+                    (77.49568462371826, 31.284468173980713),
+                    (75.94405174255371, 27.278034687042236),
+                    (72.26377010345459, 26.459040641784668),
+                    (69.18813228607178, 29.22902822494507),
+                    (69.59758758544922, 33.44217777252197),
+                    (61.81623935699463, 33.62922430038452),
+                    (60.68582057952881, 29.45535659790039),
+                    (59.555397033691406, 25.28149127960205),
+                    (58.424973487854004, 21.10762596130371),
+                    (57.2945499420166, 16.93375825881958),
+                    (56.22532844543457, 12.755473852157593),
+                    (52.76538848876953, 10.500795841217041),
+                    (48.90596866607666, 12.094271183013916),
+                    (48.308138847351074, 16.104159355163574),
+                    (49.47049140930176, 20.269246101379395),
+                    (50.63283920288086, 24.434335231781006),
+                    (51.79518699645996, 28.599424362182617),
+                    (52.95753479003906, 32.764503955841064),
+                    (48.056344985961914, 33.09859752655029),
+                    (45.441436767578125, 29.6545672416687),
+                    (42.8265380859375, 26.210553646087646),
+                ],
+            ),
+            (
+                Partie::Eclair,
+                vec![
+                    (40.21162509918213, 22.766525745391846),
+                    (37.59672403335571, 19.322509765625),
+                    (34.98181343078613, 15.8784818649292),
+                    (32.36691236495972, 12.434468269348145),
+                    (29.7520112991333, 8.990453481674194),
+                    (27.317805290222168, 9.032862186431885),
+                    (28.58949899673462, 13.165867328643799),
+                    (29.861197471618652, 17.298890352249146),
+                    (31.132893562316895, 21.431894302368164),
+                    (32.404587268829346, 25.564899444580078),
+                    (30.700385570526123, 27.5),
+                    (26.376159191131592, 27.5),
+                    (22.505464553833008, 28.17284107208252),
+                    (23.830275535583496, 32.269110679626465),
+                ],
+            ),
+        ],
+    }
+}
+
+/// Bosses du nuage de l'icon LuneNuageux (centre, rayon)
+const BOSSES_NUAGE_LUNE: [((f64, f64), f64); 3] = [
+    ((45.0, 50.0), 18.0),
+    ((78.0, 62.0), 28.0),
+    ((110.0, 50.0), 18.0),
+];
+
+/// Croissant de l'icon LuneNuageux, en partie caché par le nuage
+fn lune_nuageuse_lune() -> Vec<(f64, f64)> {
+    croissant((100.0, 105.0), 30.0)
+        .into_iter()
+        .filter(|p| !dans_bosses(&BOSSES_NUAGE_LUNE, *p, 4.0))
+        .collect()
+}
+
+/// Nuage de l'icon LuneNuageux : le contour extérieur des bosses
+fn lune_nuageuse_nuage() -> Vec<(f64, f64)> {
+    let mut coords = vec![];
+    for (centre, rayon) in &BOSSES_NUAGE_LUNE {
+        let nombre = (*rayon * 1.6) as usize;
+        coords.extend(
+            cercle(*centre, *rayon, nombre)
+                .into_iter()
+                .filter(|p| p.1 >= 32.0 && !dans_bosses(&BOSSES_NUAGE_LUNE, *p, -1.0)),
+        );
+    }
+    coords.extend(segment((45.0, 32.0), (110.0, 32.0), 16));
+    coords
+}
+
+/// Couche de glace au sol, sous la pluie verglaçante
+fn glace() -> Vec<(f64, f64)> {
+    let mut coords = segment((24.0, 4.0), (96.0, 4.0), 18);
+    for x in &[34.0, 54.0, 74.0] {
+        coords.extend(segment((*x, 4.0), (*x - 4.0, 0.0), 1));
+    }
+    coords
+}
+
+/// Grêlons à côté de l'éclair, pour l'orage avec grêle
+fn grelons() -> Vec<(f64, f64)> {
+    let mut coords = cercle((70.0, 14.0), 5.0, 8);
+    coords.extend(cercle((92.0, 22.0), 5.0, 8));
+    coords.extend(cercle((84.0, 4.0), 5.0, 8));
+    coords
+}
+
+/// Grains de sable emportés sous les bandes de la brume
+fn grains_de_sable() -> Vec<(f64, f64)> {
+    let mut coords = vec![];
+    for (i, y) in [28.0, 18.0, 8.0].iter().enumerate() {
+        let decalage = (i % 2) as f64 * 8.0;
+        let mut x = 20.0 + decalage;
+        while x < 120.0 {
+            coords.push((x, *y));
+            x += 16.0;
         }
-        AsciiIconType::Neige => {
-            vec![
-                (46.500000953674316, 25.499999523162842),
-                (47.474374771118164, 21.395795345306396),
-                (44.566779136657715, 18.6672043800354),
-                (40.53959369659424, 19.460960626602173),
-                (39.603095054626465, 23.542001247406006),
-                (42.45866298675537, 26.340162754058838),
-                (141.99999809265137, 86.99999809265137),
-                (140.1032257080078, 83.56507301330566),
-                (135.929594039917, 82.99999237060547),
-                (131.6645622253418, 82.99999237060547),
-                (127.40344047546387, 83.05932998657227),
-                (124.10223960876465, 85.59920310974121),
-                (124.2253589630127, 89.5860481262207),
-                (127.87405014038086, 91.49737358093262),
-                (132.1390438079834, 91.49999618530273),
-                (136.4040756225586, 91.49999618530273),
-                (140.5453109741211, 90.88921546936035),
-                (62.5, 34.50000047683716),
-                (63.45022678375244, 30.396487712860107),
-                (60.54156303405762, 27.659928798675537),
-                (56.520328521728516, 28.479831218719482),
-                (55.54603099822998, 32.57512092590332),
-                (58.43092441558838, 35.33210277557373),
-                (78.49999904632568, 25.499999523162842),
-                (79.47398662567139, 21.391117572784424),
-                (76.55775547027588, 18.66461753845215),
-                (72.52951622009277, 19.47080135345459),
-                (71.59152507781982, 23.56261968612671),
-                (74.45415496826172, 26.33883237838745),
-                (115.50000190734863, 87.5),
-                (115.21321296691895, 83.38025093078613),
-                (114.2923641204834, 79.35580730438232),
-                (112.68630027770996, 75.55347442626953),
-                (110.42322158813477, 72.10137367248535),
-                (110.51998138427734, 68.50734233856201),
-                (111.34061813354492, 64.46411609649658),
-                (111.49111747741699, 60.33613204956055),
-                (111.0246753692627, 56.2346887588501),
-                (109.83033180236816, 52.2836971282959),
-                (107.96671867370605, 48.59972953796387),
-                (105.5655574798584, 45.2394962310791),
-                (102.65694618225098, 42.314252853393555),
-                (99.28879737854004, 39.926090240478516),
-                (95.57753562927246, 38.11753749847412),
-                (91.61765098571777, 36.949708461761475),
-                (87.51859664916992, 36.499996185302734),
-                (83.38533401489258, 36.499996185302734),
-                (79.25207138061523, 36.499996185302734),
-                (75.11881351470947, 36.499998569488525),
-                (70.98555088043213, 36.499998569488525),
-                (66.85228824615479, 36.499998569488525),
-                (62.719035148620605, 36.499998569488525),
-                (58.58577251434326, 36.499998569488525),
-                (54.45250988006592, 36.499996185302734),
-                (50.319247245788574, 36.499996185302734),
-                (46.18598461151123, 36.499996185302734),
-                (42.05272197723389, 36.499996185302734),
-                (37.91945934295654, 36.499996185302734),
-                (33.78620624542236, 36.499996185302734),
-                (29.654231071472168, 36.559226512908936),
-                (25.57558536529541, 37.191617488861084),
-                (21.716361045837402, 38.64690065383911),
-                (18.269490003585815, 40.916175842285156),
-                (15.16369342803955, 43.63943576812744),
-                (12.46090054512024, 46.76125526428223),
-                (10.31934380531311, 50.289649963378906),
-                (8.872714042663574, 54.15507793426514),
-                (8.133713603019714, 58.216800689697266),
-                (8.034221529960632, 62.34515190124512),
-                (8.59052062034607, 66.43571853637695),
-                (9.851885437965393, 70.36612510681152),
-                (11.763291358947754, 74.02576446533203),
-                (14.198023080825806, 77.36175537109375),
-                (17.09855556488037, 80.30020713806152),
-                (20.441815853118896, 82.72212982177734),
-                (24.143381118774414, 84.55060005187988),
-                (27.27229595184326, 86.58782958984375),
-                (28.627662658691406, 90.48884391784668),
-                (30.532608032226562, 94.15277481079102),
-                (32.94403076171875, 97.50548362731934),
-                (35.78474521636963, 100.50393104553223),
-                (38.92754316329956, 103.18667411804199),
-                (42.40915298461914, 105.4046630859375),
-                (46.2134313583374, 107.00894355773926),
-                (50.219478607177734, 108.01156044006348),
-                (54.32542324066162, 108.46104621887207),
-                (58.45414638519287, 108.37997436523438),
-                (62.52033233642578, 107.66104698181152),
-                (66.44536972045898, 106.3750171661377),
-                (70.05924224853516, 105.54716110229492),
-                (73.21244239807129, 108.21518898010254),
-                (76.69713020324707, 110.43120384216309),
-                (80.4776668548584, 112.09109306335449),
-                (84.47615623474121, 113.11954498291016),
-                (88.58795166015625, 113.4968376159668),
-                (92.71085739135742, 113.26151847839355),
-                (96.74606323242188, 112.38899230957031),
-                (100.56471824645996, 110.82272529602051),
-                (104.05086517333984, 108.6093807220459),
-                (107.19371795654297, 105.93048095703125),
-                (109.90934371948242, 102.81939506530762),
-                (112.1654224395752, 99.36031341552734),
-                (113.81537437438965, 95.5760669708252),
-                (114.87814903259277, 91.58485412597656),
-                (113.00000190734863, 111.49999618530273),
-                (112.04188346862793, 115.54141998291016),
-                (114.37074661254883, 118.96865844726562),
-                (117.25990295410156, 122.06418037414551),
-                (120.1954460144043, 125.09757041931152),
-                (124.25751686096191, 125.92630386352539),
-                (127.26558685302734, 123.1857681274414),
-                (126.29246711730957, 119.30839538574219),
-                (123.30646514892578, 116.30645751953125),
-                (120.31235694885254, 113.31234931945801),
-                (117.04427719116211, 110.68181991577148),
-                (50.999999046325684, 118.99999618530273),
-                (50.03746509552002, 123.1870174407959),
-                (53.18305015563965, 125.79937934875488),
-                (57.33583450317383, 125.26982307434082),
-                (60.373191833496094, 122.1268081665039),
-                (61.34378433227539, 118.03189277648926),
-                (58.34214687347412, 115.08955955505371),
-                (54.325194358825684, 116.14983558654785),
-                (126.00000381469727, 56.500000953674316),
-                (127.47940063476562, 52.678232192993164),
-                (125.05654335021973, 49.40385818481445),
-                (120.97066879272461, 49.61935043334961),
-                (117.91254997253418, 52.587432861328125),
-                (116.50376319885254, 56.46660327911377),
-                (118.82583618164062, 59.61972713470459),
-                (122.93757438659668, 59.463562965393066),
-                (62.5, 16.00000023841858),
-                (63.47397804260254, 11.890960931777954),
-                (60.55746555328369, 9.164479374885559),
-                (56.529178619384766, 9.971134662628174),
-                (55.54499626159668, 14.06691312789917),
-                (58.42695236206055, 16.830965280532837),
-                (85.50000190734863, 123.50000381469727),
-                (84.49999809265137, 126.91903114318848),
-                (84.49999809265137, 130.52942276000977),
-                (84.49999809265137, 134.13981437683105),
-                (84.7470760345459, 137.7175521850586),
-                (87.09505081176758, 140.39512634277344),
-                (90.52124977111816, 140.61420440673828),
-                (92.72207260131836, 137.90579795837402),
-                (93.00000190734863, 134.32979583740234),
-                (93.00000190734863, 130.7193946838379),
-                (93.00000190734863, 127.1090030670166),
-                (92.18238830566406, 123.69481086730957),
-                (88.84787559509277, 122.5001335144043),
-            ]
+    }
+    coords
+}
+
+/// Points régulièrement espacés sur un segment (extrémités comprises),
+/// pour compléter les icons dessinés à la main
+fn segment(depart: (f64, f64), arrivee: (f64, f64), nombre: usize) -> Vec<(f64, f64)> {
+    let pas = nombre.max(1) as f64;
+    (0..=nombre)
+        .map(|i| {
+            let t = i as f64 / pas;
+            (
+                depart.0 + (arrivee.0 - depart.0) * t,
+                depart.1 + (arrivee.1 - depart.1) * t,
+            )
+        })
+        .collect()
+}
+
+/// Points régulièrement espacés sur un cercle
+fn cercle(centre: (f64, f64), rayon: f64, nombre: usize) -> Vec<(f64, f64)> {
+    (0..nombre)
+        .map(|i| {
+            let angle = i as f64 * 2.0 * std::f64::consts::PI / nombre as f64;
+            (
+                centre.0 + rayon * angle.cos(),
+                centre.1 + rayon * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Le point est-il à l'intérieur d'un des cercles (centre, rayon) ?
+/// La marge agrandit (ou réduit) les cercles.
+fn dans_bosses(bosses: &[((f64, f64), f64)], point: (f64, f64), marge: f64) -> bool {
+    bosses.iter().any(|(centre, rayon)| {
+        let distance = ((point.0 - centre.0).powi(2) + (point.1 - centre.1).powi(2)).sqrt();
+        distance < rayon + marge
+    })
+}
+
+/// Croissant de lune tourné vers la gauche : le bord d'un disque,
+/// moins la partie recouverte par un second disque décalé vers la droite
+fn croissant(centre: (f64, f64), rayon: f64) -> Vec<(f64, f64)> {
+    let ombre = (centre.0 + rayon * 0.45, centre.1 + rayon * 0.3);
+    let rayon_ombre = rayon * 0.85;
+    let nombre = (rayon * 1.6) as usize;
+
+    let mut coords: Vec<(f64, f64)> = cercle(centre, rayon, nombre)
+        .into_iter()
+        .filter(|p| !dans_bosses(&[(ombre, rayon_ombre)], *p, 0.0))
+        .collect();
+    coords.extend(
+        cercle(ombre, rayon_ombre, nombre)
+            .into_iter()
+            .filter(|p| dans_bosses(&[(centre, rayon)], *p, 0.0)),
+    );
+    coords
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decomposer_codes_valides() {
+        assert_eq!(decomposer_code("p1j"), Some(("p1", Some('j'))));
+        assert_eq!(decomposer_code("p5bisn"), Some(("p5bis", Some('n'))));
+        assert_eq!(decomposer_code("p22quater"), Some(("p22quater", None)));
+        assert_eq!(decomposer_code("p13terj"), Some(("p13ter", Some('j'))));
+    }
+
+    #[test]
+    fn decomposer_codes_invalides() {
+        assert_eq!(decomposer_code(""), None);
+        assert_eq!(decomposer_code("j"), None);
+        assert_eq!(decomposer_code("pj"), None);
+        assert_eq!(decomposer_code("x1j"), None);
+        assert_eq!(decomposer_code("p3autre"), None);
+    }
+
+    #[test]
+    fn codes_de_la_table_reconnus() {
+        for (code, which) in PICTOGRAMMES {
+            assert!(decomposer_code(code).is_some(), "{}", code);
+            assert_eq!(get_type_icon_from_str(&format!("{}j", code)), *which);
         }
-        AsciiIconType::Grele => {
-            vec![
-                (113.50000381469727, 113.00000190734863),
-                (112.11427688598633, 116.82796478271484),
-                (114.68562126159668, 120.18562316894531),
-                (117.7132797241211, 123.21328163146973),
-                (120.74594497680664, 126.23579025268555),
-                (124.67817306518555, 127.4675464630127),
-                (127.74320602416992, 124.7410774230957),
-                (126.8006420135498, 120.81748962402344),
-                (123.87687683105469, 117.68949508666992),
-                (120.95535278320312, 114.55928802490234),
-                (117.52745628356934, 112.15585708618164),
-                (85.50000190734863, 124.49999809265137),
-                (84.0000057220459, 128.39564323425293),
-                (84.0000057220459, 132.7346897125244),
-                (84.0000057220459, 137.07372665405273),
-                (85.47842979431152, 140.9782600402832),
-                (89.61668014526367, 141.9796085357666),
-                (92.32951164245605, 139.07808303833008),
-                (92.50000953674316, 134.75622177124023),
-                (92.50000953674316, 130.41717529296875),
-                (92.39507675170898, 126.0851001739502),
-                (89.19363021850586, 123.57404708862305),
-                (50.50000190734863, 120.50000190734863),
-                (49.772095680236816, 124.77766990661621),
-                (53.16892147064209, 127.41198539733887),
-                (57.30479717254639, 126.23875617980957),
-                (60.633039474487305, 123.23296546936035),
-                (61.25053405761719, 118.91434669494629),
-                (57.8605842590332, 116.12345695495605),
-                (53.6867094039917, 117.31329917907715),
-                (126.00000381469727, 57.5),
-                (127.40594863891602, 53.74880790710449),
-                (124.98268127441406, 50.40140628814697),
-                (121.07674598693848, 50.926856994628906),
-                (117.88983345031738, 53.665852546691895),
-                (116.50458335876465, 57.4975061416626),
-                (118.79585266113281, 60.607595443725586),
-                (122.74728775024414, 60.15826225280762),
-                (142.5, 88.00000190734863),
-                (140.35143852233887, 84.42158699035645),
-                (136.26089096069336, 83.50000381469727),
-                (131.9479465484619, 83.50000381469727),
-                (127.63588905334473, 83.52212905883789),
-                (124.20548439025879, 85.9426498413086),
-                (123.82363319396973, 90.05965232849121),
-                (127.4332332611084, 91.9992733001709),
-                (131.74617767333984, 92.00000762939453),
-                (136.05913162231445, 92.00000762939453),
-                (140.2063751220703, 91.26579284667969),
-                (115.50000190734863, 90.0),
-                (115.14244079589844, 85.69570541381836),
-                (114.05116081237793, 81.51714324951172),
-                (112.26179122924805, 77.58599758148193),
-                (109.87853050231934, 73.98247241973877),
-                (110.77169418334961, 70.02141952514648),
-                (111.42558097839355, 65.75276374816895),
-                (111.42455101013184, 61.43205642700195),
-                (110.73822021484375, 57.168240547180176),
-                (109.29325103759766, 53.099122047424316),
-                (107.15070724487305, 49.34884071350098),
-                (104.46639060974121, 45.96277713775635),
-                (101.28988265991211, 43.03791522979736),
-                (97.61421203613281, 40.77332019805908),
-                (93.56654167175293, 39.27142858505249),
-                (89.30662155151367, 38.56539011001587),
-                (84.98396873474121, 38.499999046325684),
-                (80.65973281860352, 38.499999046325684),
-                (78.65303993225098, 35.450944900512695),
-                (77.49568462371826, 31.284468173980713),
-                (75.94405174255371, 27.278034687042236),
-                (72.26377010345459, 26.459040641784668),
-                (69.18813228607178, 29.22902822494507),
-                (69.59758758544922, 33.44217777252197),
-                (70.86928367614746, 37.57519245147705),
-                (67.12021827697754, 37.99999713897705),
-                (62.94666290283203, 37.80308246612549),
-                (61.81623935699463, 33.62922430038452),
-                (60.68582057952881, 29.45535659790039),
-                (59.555397033691406, 25.28149127960205),
-                (58.424973487854004, 21.10762596130371),
-                (57.2945499420166, 16.93375825881958),
-                (56.22532844543457, 12.755473852157593),
-                (52.76538848876953, 10.500795841217041),
-                (48.90596866607666, 12.094271183013916),
-                (48.308138847351074, 16.104159355163574),
-                (49.47049140930176, 20.269246101379395),
-                (50.63283920288086, 24.434335231781006),
-                (51.79518699645996, 28.599424362182617),
-                (52.95753479003906, 32.764503955841064),
-                (53.55398654937744, 36.500000953674316),
-                (49.22976016998291, 36.500000953674316),
-                (48.056344985961914, 33.09859752655029),
-                (45.441436767578125, 29.6545672416687),
-                (42.8265380859375, 26.210553646087646),
-                (40.21162509918213, 22.766525745391846),
-                (37.59672403335571, 19.322509765625),
-                (34.98181343078613, 15.8784818649292),
-                (32.36691236495972, 12.434468269348145),
-                (29.7520112991333, 8.990453481674194),
-                (27.317805290222168, 9.032862186431885),
-                (28.58949899673462, 13.165867328643799),
-                (29.861197471618652, 17.298890352249146),
-                (31.132893562316895, 21.431894302368164),
-                (32.404587268829346, 25.564899444580078),
-                (30.700385570526123, 27.5),
-                (26.376159191131592, 27.5),
-                (22.505464553833008, 28.17284107208252),
-                (23.830275535583496, 32.269110679626465),
-                (25.26947021484375, 36.34683132171631),
-                (22.161095142364502, 38.42353582382202),
-                (18.439334630966187, 40.617499351501465),
-                (15.078015327453613, 43.33204746246338),
-                (12.104973793029785, 46.468162536621094),
-                (9.80242669582367, 50.11669635772705),
-                (8.332213163375854, 54.17590618133545),
-                (7.611165642738342, 58.434648513793945),
-                (7.558487057685852, 62.754364013671875),
-                (8.230270743370056, 67.0201063156128),
-                (9.671045541763306, 71.09079360961914),
-                (11.80001974105835, 74.84906673431396),
-                (14.486924409866333, 78.23102951049805),
-                (17.752246856689453, 81.05515480041504),
-                (21.49681806564331, 83.20592880249023),
-                (25.541558265686035, 84.72517967224121),
-                (27.362256050109863, 88.21187973022461),
-                (29.001412391662598, 92.20901489257812),
-                (31.23133420944214, 95.90888023376465),
-                (33.99304389953613, 99.23133850097656),
-                (37.181994915008545, 102.14755058288574),
-                (40.74140548706055, 104.59579467773438),
-                (44.63468551635742, 106.46754264831543),
-                (48.76811504364014, 107.7239990234375),
-                (53.03939342498779, 108.37663650512695),
-                (57.359862327575684, 108.47484588623047),
-                (61.65879726409912, 108.0373764038086),
-                (65.85286617279053, 107.00116157531738),
-                (69.76778984069824, 105.76499938964844),
-                (73.04036140441895, 108.58675956726074),
-                (76.67625427246094, 110.91992378234863),
-                (80.6358814239502, 112.64530181884766),
-                (84.8296070098877, 113.67782592773438),
-                (89.13711547851562, 113.99965286254883),
-                (93.44318389892578, 113.65428924560547),
-                (97.63041496276855, 112.59771347045898),
-                (101.54488563537598, 110.77668190002441),
-                (105.18246650695801, 108.45186233520508),
-                (108.27644348144531, 105.43985366821289),
-                (110.86856842041016, 101.98230743408203),
-                (113.08605194091797, 98.27569961547852),
-                (114.57842826843262, 94.2226505279541),
-            ]
+        for code in ["p13terj", "p13quatern", "p23j", "p23bisn"] {
+            assert_eq!(
+                get_type_icon_from_str(code),
+                AsciiIconType::PluieVerglacante,
+                "{}",
+                code
+            );
         }
-    };
+    }
 }
@@ -1,5 +1,11 @@
+use crate::ascii_icons::{self, PackIcons};
+use crate::data::Affichage;
+use crate::data::Contours;
+use crate::data::Favoris;
+use crate::data::Historique;
 use crate::data::Place;
 use crate::data::Prevision;
+use crate::data::VillesCarte;
 use crate::errors::MeteoErreurs;
 use crate::parser;
 use crate::storage::{self};
@@ -19,13 +25,14 @@ impl MeteoFranceAPI {
     /// Requêtes HTTP pour trouver une ville, retourne le résultat JSON
     pub fn get_JSON_place(&self, code_postal: i32) -> String {
         let mut arguments = HashMap::new();
-        arguments.insert(String::from("q"), String::from(code_postal.to_string()));
+        // Les codes postaux commencent parfois par un zéro (ex : 06000)
+        arguments.insert(String::from("q"), format!("{:05}", code_postal));
         arguments.insert(String::from("token"), String::from(self.token));
-        return http::request(
+        http::request(
             String::from(self.base_url),
             String::from("places"),
             arguments,
-        );
+        )
     }
 
     #[allow(unused_must_use)]
@@ -39,11 +46,11 @@ impl MeteoFranceAPI {
                     arguments.insert(String::from("lat"), lat.to_string());
                     arguments.insert(String::from("lon"), lon.to_string());
                     arguments.insert(String::from("token"), String::from(self.token));
-                    return Ok(http::request(
+                    Ok(http::request(
                         String::from(self.base_url),
                         String::from("forecast"),
                         arguments,
-                    ));
+                    ))
                 }
                 None => Err(MeteoErreurs::BadForecastLocation),
             },
@@ -62,22 +69,7 @@ impl MeteoFranceAPI {
     pub fn get_prevision(&self, place: Place) -> Result<Prevision, MeteoErreurs> {
         let json_content = self.get_JSON_forecast(place)?;
         let prevision = parser::JSONToPrevision(json_content)?;
-        return Ok(prevision);
-    }
-
-    /// Ecriture du fichier de configuration pour changer la ville par défaut
-    pub fn changer_default_ville(&self, code_postal: i32) -> bool {
-        let place_result = self.get_place(code_postal);
-        match place_result {
-            Ok(place) => {
-                let storage_request = storage::edit_default_place(place);
-                match storage_request {
-                    Ok(_) => true,
-                    Err(_) => false,
-                }
-            }
-            Err(_) => false,
-        }
+        Ok(prevision)
     }
 
     /// Lecture du fichier de configuration pour récupérer la ville par défaut
@@ -85,4 +77,88 @@ impl MeteoFranceAPI {
         let place = storage::get_default_place(self)?;
         Ok(place)
     }
+
+    /// Lecture du fichier de configuration pour récupérer les favoris
+    pub fn get_favoris(&self) -> Result<Favoris, MeteoErreurs> {
+        let favoris = storage::get_favoris()?;
+        Ok(favoris)
+    }
+
+    /// Ecriture du fichier de configuration des favoris. Le favori marqué
+    /// par défaut devient aussi la ville ouverte au démarrage.
+    pub fn enregistrer_favoris(&self, favoris: Favoris) -> bool {
+        let defaut = favoris.favoris.iter().find(|f| f.defaut).cloned();
+        if storage::edit_favoris(favoris).is_err() {
+            return false;
+        }
+        match defaut {
+            Some(f) => storage::edit_default_place(f.place).is_ok(),
+            None => true,
+        }
+    }
+
+    /// Lecture de l'historique des recherches
+    pub fn get_historique(&self) -> Result<Historique, MeteoErreurs> {
+        let historique = storage::get_historique()?;
+        Ok(historique)
+    }
+
+    /// Ecriture de l'historique des recherches
+    pub fn enregistrer_historique(&self, historique: Historique) -> bool {
+        storage::edit_historique(historique).is_ok()
+    }
+
+    /// Lecture du fichier de configuration pour récupérer les villes de la carte
+    pub fn get_villes_carte(&self) -> Result<VillesCarte, MeteoErreurs> {
+        let villes = storage::get_villes_carte()?;
+        Ok(villes)
+    }
+
+    /// Lecture du fichier de configuration pour récupérer les préférences d'affichage
+    pub fn get_affichage(&self) -> Result<Affichage, MeteoErreurs> {
+        let affichage = storage::get_affichage()?;
+        Ok(affichage)
+    }
+
+    /// Icons du pack choisi dans les préférences d'affichage, un fichier SVG
+    /// par type d'icon (ex : "pluie.svg"). Les fichiers illisibles ou dont
+    /// le nom ne correspond à aucun icon sont ignorés : ces icons gardent
+    /// le dessin fourni avec le programme.
+    pub fn get_pack_icons(&self, pack: &str) -> Result<PackIcons, MeteoErreurs> {
+        let mut icons = PackIcons::new();
+        if pack.is_empty() {
+            return Ok(icons);
+        }
+        for (nom, contenu) in storage::get_fichiers_pack_icons(pack)? {
+            let which = match ascii_icons::get_type_icon_from_nom(&nom) {
+                Some(w) => w,
+                None => continue,
+            };
+            if let Ok(icon) = parser::SVGToIcon(contenu) {
+                icons.insert(which, ascii_icons::get_calques_svg(&icon));
+            }
+        }
+        Ok(icons)
+    }
+
+    /// Contours de la carte : le tracé de la France fourni avec le programme
+    /// (remplacé par un fichier "france.geojson" du dossier de configuration
+    /// s'il existe), puis les autres fichiers GeoJSON de ce dossier.
+    /// Les fichiers illisibles sont ignorés.
+    pub fn get_contours(&self) -> Result<Vec<Contours>, MeteoErreurs> {
+        let fichiers = storage::get_fichiers_contours()?;
+        let mut contours = vec![];
+        if !fichiers.iter().any(|(nom, _)| nom == "france") {
+            contours.push(parser::JSONToContours(
+                String::from("france"),
+                String::from(ascii_icons::get_france_geojson()),
+            )?);
+        }
+        for (nom, contenu) in fichiers {
+            if let Ok(c) = parser::JSONToContours(nom, contenu) {
+                contours.push(c)
+            }
+        }
+        Ok(contours)
+    }
 }
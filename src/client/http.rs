@@ -11,17 +11,15 @@ pub fn request(base_url: String, sub_dir: String, arguments: HashMap<String, Str
     let mut url_str = base_url + sub_dir.chars().as_str() + "/";
 
     // Pour chaque argument, l'ajouter à l'URL
-    if arguments.len() > 0 {
+    if !arguments.is_empty() {
         url_str += "?";
-        let mut c = 0;
-        for i in arguments {
+        for (c, i) in arguments.into_iter().enumerate() {
             if c > 0 {
                 url_str += "&";
             }
             url_str += i.0.chars().as_str();
             url_str += "=";
             url_str += i.1.chars().as_str();
-            c += 1;
         }
     }
 
@@ -33,10 +31,8 @@ pub fn request(base_url: String, sub_dir: String, arguments: HashMap<String, Str
         Ok(mut resp) => {
             let mut content = String::new();
             resp.read_to_string(&mut content);
-            return content;
-        }
-        Err(_) => {
-            return String::from("");
+            content
         }
+        Err(_) => String::from(""),
     }
 }
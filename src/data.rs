@@ -18,7 +18,7 @@ pub struct Place {
 
 impl Default for Place {
     fn default() -> Self {
-        return Place {
+        Place {
             insee: Some(String::from("75001")),
             name: Some(String::from("Paris 01")),
             lat: Some(48.8592),
@@ -27,13 +27,13 @@ impl Default for Place {
             admin: Some(String::from("Île-de-France")),
             admin2: Some(String::from("75")),
             postCode: Some(String::from("75001")),
-        };
+        }
     }
 }
 
 impl Place {
     pub fn new() -> Self {
-        return Place {
+        Place {
             insee: None,
             name: None,
             lat: None,
@@ -42,10 +42,251 @@ impl Place {
             admin: None,
             admin2: None,
             postCode: None,
-        };
+        }
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Lieu favori de l'utilisateur : une ville associée à un libellé
+/// ("Maison", "Bureau", "Chalet"...). Un seul favori est marqué
+/// comme ville par défaut, ouverte au démarrage.
+pub struct Favori {
+    pub label: String,
+    pub defaut: bool,
+    pub place: Place,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+/// Liste des favoris telle qu'enregistrée dans le fichier de configuration
+pub struct Favoris {
+    pub favoris: Vec<Favori>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Ville affichée sur la carte de l'onglet "Carte". Sa position sur la carte
+/// et ses voisines (navigation avec les flèches) sont calculées à partir
+/// de sa latitude et de sa longitude.
+pub struct VilleCarte {
+    pub name: String,
+    pub postcode: String,
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl VilleCarte {
+    pub fn new(name: &str, postcode: &str, lat: f64, lon: f64) -> Self {
+        VilleCarte {
+            name: String::from(name),
+            postcode: String::from(postcode),
+            lat,
+            lon,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Liste des villes de la carte, lue depuis le fichier de configuration
+pub struct VillesCarte {
+    pub villes: Vec<VilleCarte>,
+}
+
+/// Par défaut, on affiche les principales villes de France
+impl Default for VillesCarte {
+    fn default() -> Self {
+        VillesCarte {
+            villes: vec![
+                VilleCarte::new("Paris", "75001", 48.8592, 2.3417),
+                VilleCarte::new("Marseille", "13000", 43.29667, 5.37639),
+                VilleCarte::new("Lyon", "69000", 45.75889, 4.84139),
+                VilleCarte::new("Toulouse", "31000", 43.60444, 1.44194),
+                VilleCarte::new("Nice", "06000", 43.70194, 7.26833),
+                VilleCarte::new("Nantes", "44000", 47.21722, -1.55389),
+                VilleCarte::new("Strasbourg", "67000", 48.58361, 7.74806),
+                VilleCarte::new("Bordeaux", "33000", 44.83778, -0.57944),
+                VilleCarte::new("Lille", "59000", 50.63194, 3.0575),
+                VilleCarte::new("Ajaccio", "20000", 41.92667, 8.73694),
+                VilleCarte::new("Pointe-à-Pitre", "97110", 16.24111, -61.53306),
+                VilleCarte::new("Fort-de-France", "97200", 14.61611, -61.05889),
+                VilleCarte::new("Cayenne", "97300", 4.93722, -52.32600),
+                VilleCarte::new("Saint-Denis", "97400", -20.88210, 55.45070),
+                VilleCarte::new("Mamoudzou", "97600", -12.78056, 45.22778),
+            ],
+        }
+    }
+}
+
+impl VillesCarte {
+    /// Préfectures des départements, affichées en plus des villes
+    /// de la carte lorsque l'on zoome
+    pub fn prefectures() -> Self {
+        VillesCarte {
+            villes: vec![
+                VilleCarte::new("Bourg-en-Bresse", "01000", 46.2052, 5.2255),
+                VilleCarte::new("Laon", "02000", 49.5639, 3.6244),
+                VilleCarte::new("Moulins", "03000", 46.5646, 3.3326),
+                VilleCarte::new("Digne-les-Bains", "04000", 44.0925, 6.2356),
+                VilleCarte::new("Gap", "05000", 44.5594, 6.0786),
+                VilleCarte::new("Nice", "06000", 43.7102, 7.2620),
+                VilleCarte::new("Privas", "07000", 44.7353, 4.5992),
+                VilleCarte::new("Charleville-Mézières", "08000", 49.7621, 4.7263),
+                VilleCarte::new("Foix", "09000", 42.9653, 1.6072),
+                VilleCarte::new("Troyes", "10000", 48.2973, 4.0744),
+                VilleCarte::new("Carcassonne", "11000", 43.2130, 2.3491),
+                VilleCarte::new("Rodez", "12000", 44.3506, 2.5750),
+                VilleCarte::new("Marseille", "13000", 43.2965, 5.3698),
+                VilleCarte::new("Caen", "14000", 49.1829, -0.3707),
+                VilleCarte::new("Aurillac", "15000", 44.9264, 2.4397),
+                VilleCarte::new("Angoulême", "16000", 45.6484, 0.1562),
+                VilleCarte::new("La Rochelle", "17000", 46.1603, -1.1511),
+                VilleCarte::new("Bourges", "18000", 47.0810, 2.3988),
+                VilleCarte::new("Tulle", "19000", 45.2658, 1.7722),
+                VilleCarte::new("Ajaccio", "20000", 41.9192, 8.7386),
+                VilleCarte::new("Bastia", "20200", 42.6977, 9.4508),
+                VilleCarte::new("Dijon", "21000", 47.3220, 5.0415),
+                VilleCarte::new("Saint-Brieuc", "22000", 48.5141, -2.7603),
+                VilleCarte::new("Guéret", "23000", 46.1710, 1.8717),
+                VilleCarte::new("Périgueux", "24000", 45.1840, 0.7210),
+                VilleCarte::new("Besançon", "25000", 47.2378, 6.0241),
+                VilleCarte::new("Valence", "26000", 44.9334, 4.8924),
+                VilleCarte::new("Évreux", "27000", 49.0241, 1.1508),
+                VilleCarte::new("Chartres", "28000", 48.4439, 1.4890),
+                VilleCarte::new("Quimper", "29000", 47.9960, -4.1020),
+                VilleCarte::new("Nîmes", "30000", 43.8367, 4.3601),
+                VilleCarte::new("Toulouse", "31000", 43.6047, 1.4442),
+                VilleCarte::new("Auch", "32000", 43.6460, 0.5857),
+                VilleCarte::new("Bordeaux", "33000", 44.8378, -0.5792),
+                VilleCarte::new("Montpellier", "34000", 43.6108, 3.8767),
+                VilleCarte::new("Rennes", "35000", 48.1173, -1.6778),
+                VilleCarte::new("Châteauroux", "36000", 46.8103, 1.6913),
+                VilleCarte::new("Tours", "37000", 47.3941, 0.6848),
+                VilleCarte::new("Grenoble", "38000", 45.1885, 5.7245),
+                VilleCarte::new("Lons-le-Saunier", "39000", 46.6744, 5.5547),
+                VilleCarte::new("Mont-de-Marsan", "40000", 43.8902, -0.4993),
+                VilleCarte::new("Blois", "41000", 47.5861, 1.3359),
+                VilleCarte::new("Saint-Étienne", "42000", 45.4397, 4.3872),
+                VilleCarte::new("Le Puy-en-Velay", "43000", 45.0434, 3.8858),
+                VilleCarte::new("Nantes", "44000", 47.2184, -1.5536),
+                VilleCarte::new("Orléans", "45000", 47.9030, 1.9093),
+                VilleCarte::new("Cahors", "46000", 44.4475, 1.4419),
+                VilleCarte::new("Agen", "47000", 44.2033, 0.6163),
+                VilleCarte::new("Mende", "48000", 44.5181, 3.5006),
+                VilleCarte::new("Angers", "49000", 47.4784, -0.5632),
+                VilleCarte::new("Saint-Lô", "50000", 49.1157, -1.0906),
+                VilleCarte::new("Châlons-en-Champagne", "51000", 48.9566, 4.3631),
+                VilleCarte::new("Chaumont", "52000", 48.1113, 5.1392),
+                VilleCarte::new("Laval", "53000", 48.0706, -0.7734),
+                VilleCarte::new("Nancy", "54000", 48.6921, 6.1844),
+                VilleCarte::new("Bar-le-Duc", "55000", 48.7727, 5.1600),
+                VilleCarte::new("Vannes", "56000", 47.6582, -2.7608),
+                VilleCarte::new("Metz", "57000", 49.1193, 6.1757),
+                VilleCarte::new("Nevers", "58000", 46.9896, 3.1590),
+                VilleCarte::new("Lille", "59000", 50.6292, 3.0573),
+                VilleCarte::new("Beauvais", "60000", 49.4295, 2.0807),
+                VilleCarte::new("Alençon", "61000", 48.4329, 0.0913),
+                VilleCarte::new("Arras", "62000", 50.2910, 2.7775),
+                VilleCarte::new("Clermont-Ferrand", "63000", 45.7772, 3.0870),
+                VilleCarte::new("Pau", "64000", 43.2951, -0.3708),
+                VilleCarte::new("Tarbes", "65000", 43.2328, 0.0781),
+                VilleCarte::new("Perpignan", "66000", 42.6887, 2.8948),
+                VilleCarte::new("Strasbourg", "67000", 48.5734, 7.7521),
+                VilleCarte::new("Colmar", "68000", 48.0794, 7.3585),
+                VilleCarte::new("Lyon", "69000", 45.7640, 4.8357),
+                VilleCarte::new("Vesoul", "70000", 47.6199, 6.1547),
+                VilleCarte::new("Mâcon", "71000", 46.3069, 4.8287),
+                VilleCarte::new("Le Mans", "72000", 48.0061, 0.1996),
+                VilleCarte::new("Chambéry", "73000", 45.5646, 5.9178),
+                VilleCarte::new("Annecy", "74000", 45.8992, 6.1294),
+                VilleCarte::new("Paris", "75001", 48.8566, 2.3522),
+                VilleCarte::new("Rouen", "76000", 49.4432, 1.0999),
+                VilleCarte::new("Melun", "77000", 48.5421, 2.6554),
+                VilleCarte::new("Versailles", "78000", 48.8049, 2.1204),
+                VilleCarte::new("Niort", "79000", 46.3237, -0.4588),
+                VilleCarte::new("Amiens", "80000", 49.8941, 2.2958),
+                VilleCarte::new("Albi", "81000", 43.9289, 2.1464),
+                VilleCarte::new("Montauban", "82000", 44.0176, 1.3550),
+                VilleCarte::new("Toulon", "83000", 43.1242, 5.9280),
+                VilleCarte::new("Avignon", "84000", 43.9493, 4.8055),
+                VilleCarte::new("La Roche-sur-Yon", "85000", 46.6705, -1.4260),
+                VilleCarte::new("Poitiers", "86000", 46.5802, 0.3404),
+                VilleCarte::new("Limoges", "87000", 45.8336, 1.2611),
+                VilleCarte::new("Épinal", "88000", 48.1724, 6.4496),
+                VilleCarte::new("Auxerre", "89000", 47.7982, 3.5673),
+                VilleCarte::new("Belfort", "90000", 47.6397, 6.8638),
+                VilleCarte::new("Évry-Courcouronnes", "91000", 48.6290, 2.4410),
+                VilleCarte::new("Nanterre", "92000", 48.8924, 2.2071),
+                VilleCarte::new("Bobigny", "93000", 48.9077, 2.4397),
+                VilleCarte::new("Créteil", "94000", 48.7904, 2.4556),
+                VilleCarte::new("Cergy", "95000", 49.0364, 2.0761),
+                VilleCarte::new("Basse-Terre", "97100", 15.9985, -61.7261),
+                VilleCarte::new("Fort-de-France", "97200", 14.6161, -61.0588),
+                VilleCarte::new("Cayenne", "97300", 4.9372, -52.3260),
+                VilleCarte::new("Saint-Denis", "97400", -20.8821, 55.4507),
+                VilleCarte::new("Mamoudzou", "97600", -12.7806, 45.2278),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+/// Jeu de symboles utilisé pour les icons compacts (cartes des jours,
+/// heure par heure, carte de France, sortie en ligne de commande)
+pub enum Symboles {
+    /// Symboles Unicode et emoji (☀ ⛅ 🌧...), sur une ou deux cellules
+    #[default]
+    Emoji,
+    /// Caractères ASCII uniquement, pour les terminaux sans emoji
+    Ascii,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+/// Préférences d'affichage, lues depuis le fichier de configuration
+pub struct Affichage {
+    pub symboles: Symboles,
+    /// Nom du pack d'icons (dossier "icones/<nom>" de la configuration),
+    /// les icons fournis avec le programme sont utilisés s'il est vide
+    pub pack_icons: String,
+    /// Nom du fichier GeoJSON (dossier "contours" de la configuration) qui
+    /// forme le tracé principal de la carte, "france" s'il est vide
+    pub contour: String,
+}
+
+#[derive(Debug, Clone)]
+/// Contours lus depuis un fichier GeoJSON : chaque anneau des polygones
+/// (extérieur ou trou) est une liste de points (latitude, longitude)
+pub struct Contours {
+    pub nom: String,
+    pub anneaux: Vec<Vec<(f64, f64)>>,
+}
+
+#[derive(Debug, Clone)]
+/// Icon lu depuis un fichier SVG d'un pack d'icons : chaque élément
+/// dessiné (polyline, polygon, path) donne un tracé de points, dans le
+/// repère des icons, avec la couleur indiquée dans le fichier
+pub struct IconSVG {
+    pub traces: Vec<TraceSVG>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TraceSVG {
+    pub couleur: Option<String>,
+    pub points: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Recherche réussie : le texte saisi et le nom de la commune trouvée
+pub struct Recherche {
+    pub requete: String,
+    pub commune: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+/// Historique des recherches, de la plus récente à la plus ancienne
+pub struct Historique {
+    pub recherches: Vec<Recherche>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 /// Structure qui reprend le squelette de l'API Météo France
 /// Ici dans le cas des données des prévisions (sous-repertoire /forecast/)
@@ -58,12 +299,12 @@ pub struct Prevision {
 
 impl Prevision {
     pub fn new() -> Self {
-        return Prevision {
+        Prevision {
             update_time: None,
             typee: None,
             geometry: None,
             properties: None,
-        };
+        }
     }
 }
 
@@ -75,10 +316,10 @@ pub struct Geometry {
 
 impl Geometry {
     pub fn new() -> Self {
-        return Geometry {
+        Geometry {
             typee: None,
             coordinates: None,
-        };
+        }
     }
 }
 
@@ -99,7 +340,7 @@ pub struct Properties {
 
 impl Properties {
     pub fn new() -> Self {
-        return Properties {
+        Properties {
             altitude: None,
             name: None,
             country: None,
@@ -111,7 +352,7 @@ impl Properties {
             daily_forecast: None,
             forecast: None,
             probability_forecast: None,
-        };
+        }
     }
 }
 
@@ -133,7 +374,7 @@ pub struct DailyForecast {
 
 impl DailyForecast {
     pub fn new() -> Self {
-        return DailyForecast {
+        DailyForecast {
             time: None,
             t_min: None,
             t_max: None,
@@ -146,7 +387,7 @@ impl DailyForecast {
             daily_weather_description: None,
             sunrise_time: None,
             sunset_time: None,
-        };
+        }
     }
 }
 
@@ -180,7 +421,7 @@ pub struct Forecast {
 
 impl Forecast {
     pub fn new() -> Forecast {
-        return Forecast {
+        Forecast {
             time: None,
             t: None,
             t_windchill: None,
@@ -205,7 +446,7 @@ impl Forecast {
             total_cloud_cover: None,
             weather_icon: None,
             weather_description: None,
-        };
+        }
     }
 }
 
@@ -222,7 +463,7 @@ pub struct ProbabilityForecast {
 
 impl ProbabilityForecast {
     pub fn new() -> Self {
-        return ProbabilityForecast {
+        ProbabilityForecast {
             time: None,
             rain_hazard_3h: None,
             rain_hazard_6h: None,
@@ -230,6 +471,6 @@ impl ProbabilityForecast {
             snow_hazard_6h: None,
             freezing_hazard: None,
             storm_hazard: None,
-        };
+        }
     }
 }
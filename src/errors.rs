@@ -3,5 +3,6 @@
 pub enum MeteoErreurs {
     BadForecastLocation,
     BadPlaceRequest,
+    BadStorageRequest,
     StringParseErreur,
 }
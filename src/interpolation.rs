@@ -0,0 +1,101 @@
+/// Puissance de la pondération par l'inverse de la distance :
+/// plus elle est grande, plus les villes proches l'emportent
+pub const PUISSANCE_IDW: f64 = 2.0;
+
+/// Interpolation d'une valeur au point (x, y) par pondération inverse à la
+/// distance (IDW) : chaque point connu (x, y, valeur) compte pour 1 / d^p.
+/// Sur un point connu, on renvoie directement sa valeur.
+pub fn interpoler_idw(points: &[(f64, f64, f64)], x: f64, y: f64) -> Option<f64> {
+    let mut somme_poids = 0.0;
+    let mut somme_valeurs = 0.0;
+
+    for (px, py, valeur) in points {
+        let distance = ((px - x).powi(2) + (py - y).powi(2)).sqrt();
+        if distance < f64::EPSILON {
+            return Some(*valeur);
+        }
+        let poids = 1.0 / distance.powf(PUISSANCE_IDW);
+        somme_poids += poids;
+        somme_valeurs += poids * valeur;
+    }
+
+    if somme_poids == 0.0 {
+        return None;
+    }
+    Some(somme_valeurs / somme_poids)
+}
+
+/// Le point (x, y) est-il à l'intérieur du polygone ?
+/// (lancer de rayon : on compte les côtés traversés vers la droite)
+pub fn dans_polygone(polygone: &[(f64, f64)], x: f64, y: f64) -> bool {
+    let mut dedans = false;
+    let mut j = polygone.len().wrapping_sub(1);
+
+    for (i, &(xi, yi)) in polygone.iter().enumerate() {
+        let (xj, yj) = polygone[j];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            dedans = !dedans;
+        }
+        j = i;
+    }
+
+    dedans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idw_sur_un_point_connu() {
+        let points = vec![(0.0, 0.0, 10.0), (10.0, 0.0, 20.0)];
+        assert_eq!(interpoler_idw(&points, 0.0, 0.0), Some(10.0));
+        assert_eq!(interpoler_idw(&points, 10.0, 0.0), Some(20.0));
+    }
+
+    #[test]
+    fn idw_entre_deux_points() {
+        let points = vec![(0.0, 0.0, 10.0), (10.0, 0.0, 20.0)];
+        // A égale distance : la moyenne
+        assert_eq!(interpoler_idw(&points, 5.0, 3.0), Some(15.0));
+        // Plus près du premier point : plus près de sa valeur
+        let valeur = interpoler_idw(&points, 2.0, 0.0).unwrap();
+        assert!(valeur > 10.0 && valeur < 15.0);
+    }
+
+    #[test]
+    fn idw_sans_point() {
+        assert_eq!(interpoler_idw(&[], 1.0, 1.0), None);
+    }
+
+    #[test]
+    fn point_dans_un_carre() {
+        let carre = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        assert!(dans_polygone(&carre, 5.0, 5.0));
+        assert!(!dans_polygone(&carre, 15.0, 5.0));
+        assert!(!dans_polygone(&carre, 5.0, -1.0));
+    }
+
+    #[test]
+    fn point_dans_un_polygone_concave() {
+        // Un "U" : le creux n'est pas à l'intérieur
+        let u = vec![
+            (0.0, 0.0),
+            (30.0, 0.0),
+            (30.0, 30.0),
+            (20.0, 30.0),
+            (20.0, 10.0),
+            (10.0, 10.0),
+            (10.0, 30.0),
+            (0.0, 30.0),
+        ];
+        assert!(dans_polygone(&u, 5.0, 20.0));
+        assert!(!dans_polygone(&u, 15.0, 20.0));
+        assert!(dans_polygone(&u, 15.0, 5.0));
+    }
+
+    #[test]
+    fn polygone_vide() {
+        assert!(!dans_polygone(&[], 0.0, 0.0));
+    }
+}
@@ -2,7 +2,9 @@ mod ascii_icons;
 mod client;
 mod data;
 mod errors;
+mod interpolation;
 mod parser;
+mod projection;
 mod storage;
 mod ui;
 mod util;
@@ -22,16 +24,22 @@ fn main() {
         // ... on récupère les commandes et on agit en conséquence ...
         let command = args[1].clone();
         if command.eq(String::from("carte").as_str()) {
-            ui::init(ui::MeteoTabs::TabCarte, None, &METEO_CLIENT);
+            ui::init(ui::MeteoTabs::Carte, None, &METEO_CLIENT);
+        } else if command.eq(String::from("resume").as_str()) {
+            match args.get(2).map(|c| c.parse::<i32>()) {
+                Some(Ok(code)) => resume(Some(code)),
+                Some(Err(_)) => println!("Code postal incorrect."),
+                None => resume(None),
+            }
         } else if command.eq(String::from("help").as_str()) {
             help();
         } else if command.len() == 5 {
             match command.parse::<i32>() {
                 Ok(code) => {
-                    ui::init(ui::MeteoTabs::TabPrevision, Some(code), &METEO_CLIENT);
+                    ui::init(ui::MeteoTabs::Prevision, Some(code), &METEO_CLIENT);
                 }
                 Err(_) => {
-                    print!("Code postal incorrect.\n");
+                    println!("Code postal incorrect.");
                 }
             }
         } else {
@@ -40,7 +48,51 @@ fn main() {
         }
     } else {
         // ... sinon on ouvre par défaut l'onglet "prévisions"
-        ui::init(ui::MeteoTabs::TabPrevision, None, &METEO_CLIENT);
+        ui::init(ui::MeteoTabs::Prevision, None, &METEO_CLIENT);
+    }
+}
+
+/// Résumé des prévisions des prochains jours, une ligne par jour,
+/// affiché directement dans le terminal (sans ouvrir l'interface)
+fn resume(code_postal: Option<i32>) {
+    let place = match code_postal {
+        Some(code) => METEO_CLIENT.get_place(code),
+        None => METEO_CLIENT.get_default_place(),
+    };
+    let place = match place {
+        Ok(p) => p,
+        Err(_) => {
+            println!("Ville introuvable.");
+            return;
+        }
+    };
+    let prevision = match METEO_CLIENT.get_prevision(place.clone()) {
+        Ok(p) => p,
+        Err(_) => {
+            println!("Prévisions indisponibles.");
+            return;
+        }
+    };
+    let symboles = METEO_CLIENT.get_affichage().unwrap_or_default().symboles;
+
+    println!("{}", place.name.unwrap_or_default());
+    let jours = prevision
+        .properties
+        .and_then(|p| p.daily_forecast)
+        .unwrap_or_default();
+    for jour in jours {
+        let date = util::DateHeure::get_dateheure_from_str(jour.time.unwrap_or_default());
+        let icon = ascii_icons::get_type_icon_from_str(
+            jour.daily_weather_icon.unwrap_or_default().as_str(),
+        );
+        println!(
+            "{}  {}  ↑ {:>3.0}°c ↓ {:>3.0}°c  {}",
+            date.format("%d/%m"),
+            ascii_icons::get_glyphe_aligne(icon, symboles),
+            jour.t_max.unwrap_or(0.0),
+            jour.t_min.unwrap_or(0.0),
+            jour.daily_weather_description.unwrap_or_default()
+        );
     }
 }
 
@@ -50,6 +102,7 @@ fn help() {
       meteo                   Ouvrir les prévisions météo de la ville par défaut
       meteo carte             Ouvrir les prévisions météo des principales villes de France
       meteo <code postal>     Ouvrir les prévisions météo d'une ville particulière
+      meteo resume [<code>]   Afficher un résumé des prochains jours dans le terminal
     \n"
     );
 }
@@ -25,7 +25,7 @@ pub fn JSONToPlace(string: String) -> Result<data::Place, MeteoErreurs> {
                 let places = json_place.as_array();
                 match places {
                     Some(p) => {
-                        if p.len() > 0 {
+                        if !p.is_empty() {
                             let j = p[0].to_owned();
                             let mut p = Place::new();
                             p.insee = value_to_string(j["insee"].to_owned());
@@ -36,15 +36,15 @@ pub fn JSONToPlace(string: String) -> Result<data::Place, MeteoErreurs> {
                             p.admin = value_to_string(j["admin"].to_owned());
                             p.admin2 = value_to_string(j["admin2"].to_owned());
                             p.postCode = value_to_string(j["postCode"].to_owned());
-                            return Ok(p);
+                            Ok(p)
                         } else {
-                            return Err(MeteoErreurs::StringParseErreur);
+                            Err(MeteoErreurs::StringParseErreur)
                         }
                     }
                     None => Err(MeteoErreurs::StringParseErreur),
                 }
             } else {
-                return Err(MeteoErreurs::StringParseErreur);
+                Err(MeteoErreurs::StringParseErreur)
             }
         }
         Err(_) => Err(MeteoErreurs::StringParseErreur),
@@ -68,17 +68,13 @@ pub fn JSONToPrevision(string: String) -> Result<data::Prevision, MeteoErreurs>
                 geometry.typee = value_to_string(json_previsions["geometry"]["type"].to_owned());
                 let mut coordinates = vec![];
                 if json_previsions["geometry"]["coordinates"].is_array() {
-                    match json_previsions["geometry"]["coordinates"].as_array() {
-                        Some(v) => {
-                            for c in v {
-                                let coord = value_to_f64(c.to_owned());
-                                match coord {
-                                    Some(f) => coordinates.push(f),
-                                    None => {}
-                                }
+                    if let Some(v) = json_previsions["geometry"]["coordinates"].as_array() {
+                        for c in v {
+                            let coord = value_to_f64(c.to_owned());
+                            if let Some(f) = coord {
+                                coordinates.push(f)
                             }
                         }
-                        None => {}
                     }
                     if coordinates.len() == 2 {
                         geometry.coordinates = Some(coordinates);
@@ -117,39 +113,35 @@ pub fn JSONToPrevision(string: String) -> Result<data::Prevision, MeteoErreurs>
                 // Daily Forecast
                 if json_previsions["properties"]["daily_forecast"].is_array() {
                     let mut daily_forecast = vec![];
-                    match json_previsions["properties"]["daily_forecast"].as_array() {
-                        Some(daily) => {
-                            for d in daily {
-                                let item = d.to_owned();
-                                if item.is_object() {
-                                    let mut s = DailyForecast::new();
-                                    s.time = value_to_string(item["time"].to_owned());
-                                    s.t_min = value_to_f64(item["T_min"].to_owned());
-                                    s.t_max = value_to_f64(item["T_max"].to_owned());
-                                    s.t_sea = value_to_f64(item["T_sea"].to_owned());
-                                    s.relative_humidity_max =
-                                        value_to_i64(item["relative_humidity_max"].to_owned());
-                                    s.relative_humidity_min =
-                                        value_to_i64(item["relative_humidity_min"].to_owned());
-                                    s.total_precipitation_24h =
-                                        value_to_f64(item["total_precipitation_24h"].to_owned());
-                                    s.uv_index = value_to_f64(item["uv_index"].to_owned());
-                                    s.daily_weather_icon =
-                                        value_to_string(item["daily_weather_icon"].to_owned());
-                                    s.daily_weather_description = value_to_string(
-                                        item["daily_weather_description"].to_owned(),
-                                    );
-                                    s.sunrise_time =
-                                        value_to_string(item["sunrise_time"].to_owned());
-                                    s.sunset_time = value_to_string(item["sunset_time"].to_owned());
-                                    daily_forecast.push(s);
-                                }
+                    if let Some(daily) = json_previsions["properties"]["daily_forecast"].as_array()
+                    {
+                        for d in daily {
+                            let item = d.to_owned();
+                            if item.is_object() {
+                                let mut s = DailyForecast::new();
+                                s.time = value_to_string(item["time"].to_owned());
+                                s.t_min = value_to_f64(item["T_min"].to_owned());
+                                s.t_max = value_to_f64(item["T_max"].to_owned());
+                                s.t_sea = value_to_f64(item["T_sea"].to_owned());
+                                s.relative_humidity_max =
+                                    value_to_i64(item["relative_humidity_max"].to_owned());
+                                s.relative_humidity_min =
+                                    value_to_i64(item["relative_humidity_min"].to_owned());
+                                s.total_precipitation_24h =
+                                    value_to_f64(item["total_precipitation_24h"].to_owned());
+                                s.uv_index = value_to_f64(item["uv_index"].to_owned());
+                                s.daily_weather_icon =
+                                    value_to_string(item["daily_weather_icon"].to_owned());
+                                s.daily_weather_description =
+                                    value_to_string(item["daily_weather_description"].to_owned());
+                                s.sunrise_time = value_to_string(item["sunrise_time"].to_owned());
+                                s.sunset_time = value_to_string(item["sunset_time"].to_owned());
+                                daily_forecast.push(s);
                             }
                         }
-                        None => {}
                     }
 
-                    if daily_forecast.len() > 0 {
+                    if !daily_forecast.is_empty() {
                         properties.daily_forecast = Some(daily_forecast);
                     } else {
                         properties.daily_forecast = None;
@@ -161,52 +153,47 @@ pub fn JSONToPrevision(string: String) -> Result<data::Prevision, MeteoErreurs>
                 // Forecast
                 if json_previsions["properties"]["forecast"].is_array() {
                     let mut forecast = vec![];
-                    match json_previsions["properties"]["forecast"].as_array() {
-                        Some(fc) => {
-                            for d in fc {
-                                let item = d.to_owned();
-                                if item.is_object() {
-                                    let mut s = Forecast::new();
-                                    s.time = value_to_string(item["time"].to_owned());
-                                    s.t = value_to_f64(item["T"].to_owned());
-                                    s.t_windchill = value_to_f64(item["T_windchill"].to_owned());
-                                    s.relative_humidity =
-                                        value_to_i64(item["relative_humidity"].to_owned());
-                                    s.p_sea = value_to_f64(item["P_sea"].to_owned());
-                                    s.wind_speed = value_to_f64(item["wind_speed"].to_owned());
-                                    s.wind_speed_gust =
-                                        value_to_f64(item["wind_speed_gust"].to_owned());
-                                    s.wind_direction =
-                                        value_to_f64(item["wind_direction"].to_owned());
-                                    s.wind_icon = value_to_string(item["wind_icon"].to_owned());
-                                    s.rain_1h = value_to_f64(item["rain_1h"].to_owned());
-                                    s.rain_3h = value_to_f64(item["rain_3h"].to_owned());
-                                    s.rain_6h = value_to_f64(item["rain_6h"].to_owned());
-                                    s.rain_12h = value_to_f64(item["rain_12h"].to_owned());
-                                    s.rain_24h = value_to_f64(item["rain_24h"].to_owned());
-                                    s.snow_1h = value_to_f64(item["snow_1h"].to_owned());
-                                    s.snow_3h = value_to_f64(item["snow_3h"].to_owned());
-                                    s.snow_6h = value_to_f64(item["snow_6h"].to_owned());
-                                    s.snow_12h = value_to_f64(item["snow_12h"].to_owned());
-                                    s.snow_24h = value_to_f64(item["snow_24h"].to_owned());
-                                    s.iso0 = value_to_i64(item["iso0"].to_owned());
-                                    s.rain_snow_limit =
-                                        value_to_string(item["rain_snow_limit"].to_owned());
-                                    s.total_cloud_cover =
-                                        value_to_i64(item["total_cloud_cover"].to_owned());
-                                    s.weather_icon =
-                                        value_to_string(item["weather_icon"].to_owned());
-                                    s.weather_description =
-                                        value_to_string(item["weather_description"].to_owned());
-
-                                    forecast.push(s);
-                                }
+                    if let Some(fc) = json_previsions["properties"]["forecast"].as_array() {
+                        for d in fc {
+                            let item = d.to_owned();
+                            if item.is_object() {
+                                let mut s = Forecast::new();
+                                s.time = value_to_string(item["time"].to_owned());
+                                s.t = value_to_f64(item["T"].to_owned());
+                                s.t_windchill = value_to_f64(item["T_windchill"].to_owned());
+                                s.relative_humidity =
+                                    value_to_i64(item["relative_humidity"].to_owned());
+                                s.p_sea = value_to_f64(item["P_sea"].to_owned());
+                                s.wind_speed = value_to_f64(item["wind_speed"].to_owned());
+                                s.wind_speed_gust =
+                                    value_to_f64(item["wind_speed_gust"].to_owned());
+                                s.wind_direction = value_to_f64(item["wind_direction"].to_owned());
+                                s.wind_icon = value_to_string(item["wind_icon"].to_owned());
+                                s.rain_1h = value_to_f64(item["rain_1h"].to_owned());
+                                s.rain_3h = value_to_f64(item["rain_3h"].to_owned());
+                                s.rain_6h = value_to_f64(item["rain_6h"].to_owned());
+                                s.rain_12h = value_to_f64(item["rain_12h"].to_owned());
+                                s.rain_24h = value_to_f64(item["rain_24h"].to_owned());
+                                s.snow_1h = value_to_f64(item["snow_1h"].to_owned());
+                                s.snow_3h = value_to_f64(item["snow_3h"].to_owned());
+                                s.snow_6h = value_to_f64(item["snow_6h"].to_owned());
+                                s.snow_12h = value_to_f64(item["snow_12h"].to_owned());
+                                s.snow_24h = value_to_f64(item["snow_24h"].to_owned());
+                                s.iso0 = value_to_i64(item["iso0"].to_owned());
+                                s.rain_snow_limit =
+                                    value_to_string(item["rain_snow_limit"].to_owned());
+                                s.total_cloud_cover =
+                                    value_to_i64(item["total_cloud_cover"].to_owned());
+                                s.weather_icon = value_to_string(item["weather_icon"].to_owned());
+                                s.weather_description =
+                                    value_to_string(item["weather_description"].to_owned());
+
+                                forecast.push(s);
                             }
                         }
-                        None => {}
                     }
 
-                    if forecast.len() > 0 {
+                    if !forecast.is_empty() {
                         properties.forecast = Some(forecast);
                     } else {
                         properties.forecast = None;
@@ -218,32 +205,27 @@ pub fn JSONToPrevision(string: String) -> Result<data::Prevision, MeteoErreurs>
                 // Probability Forecast
                 if json_previsions["properties"]["probability_forecast"].is_array() {
                     let mut probability = vec![];
-                    match json_previsions["properties"]["probability_forecast"].as_array() {
-                        Some(fc) => {
-                            for d in fc {
-                                let item = d.to_owned();
-                                if item.is_object() {
-                                    let mut s = ProbabilityForecast::new();
-                                    s.time = value_to_string(item["time"].to_owned());
-                                    s.rain_hazard_3h =
-                                        value_to_i64(item["rain_hazard_3h"].to_owned());
-                                    s.rain_hazard_6h =
-                                        value_to_i64(item["rain_hazard_6h"].to_owned());
-                                    s.snow_hazard_3h =
-                                        value_to_i64(item["snow_hazard_3h"].to_owned());
-                                    s.snow_hazard_6h =
-                                        value_to_i64(item["snow_hazard_6h"].to_owned());
-                                    s.freezing_hazard =
-                                        value_to_i64(item["freezing_hazard"].to_owned());
-                                    s.storm_hazard = value_to_i64(item["storm_hazard"].to_owned());
-                                    probability.push(s);
-                                }
+                    if let Some(fc) =
+                        json_previsions["properties"]["probability_forecast"].as_array()
+                    {
+                        for d in fc {
+                            let item = d.to_owned();
+                            if item.is_object() {
+                                let mut s = ProbabilityForecast::new();
+                                s.time = value_to_string(item["time"].to_owned());
+                                s.rain_hazard_3h = value_to_i64(item["rain_hazard_3h"].to_owned());
+                                s.rain_hazard_6h = value_to_i64(item["rain_hazard_6h"].to_owned());
+                                s.snow_hazard_3h = value_to_i64(item["snow_hazard_3h"].to_owned());
+                                s.snow_hazard_6h = value_to_i64(item["snow_hazard_6h"].to_owned());
+                                s.freezing_hazard =
+                                    value_to_i64(item["freezing_hazard"].to_owned());
+                                s.storm_hazard = value_to_i64(item["storm_hazard"].to_owned());
+                                probability.push(s);
                             }
                         }
-                        None => {}
                     }
 
-                    if probability.len() > 0 {
+                    if !probability.is_empty() {
                         properties.probability_forecast = Some(probability);
                     } else {
                         properties.probability_forecast = None;
@@ -257,8 +239,641 @@ pub fn JSONToPrevision(string: String) -> Result<data::Prevision, MeteoErreurs>
                 prevision.properties = None;
             }
 
-            return Ok(prevision);
+            Ok(prevision)
         }
         Err(_) => Err(MeteoErreurs::StringParseErreur),
     }
 }
+
+#[allow(non_snake_case)]
+/// Fonction qui lit un contenu GeoJSON (FeatureCollection, Feature ou simple
+/// géométrie) et en extrait les anneaux des polygones et multipolygones,
+/// en points (latitude, longitude). Les autres géométries sont ignorées.
+pub fn JSONToContours(nom: String, string: String) -> Result<data::Contours, MeteoErreurs> {
+    let v = serde_json::from_str::<Value>(string.as_str());
+    match v {
+        Ok(json_contours) => {
+            let mut anneaux = vec![];
+            geojson_anneaux(&json_contours, &mut anneaux);
+            if anneaux.is_empty() {
+                return Err(MeteoErreurs::StringParseErreur);
+            }
+            Ok(data::Contours { nom, anneaux })
+        }
+        Err(_) => Err(MeteoErreurs::StringParseErreur),
+    }
+}
+
+/// Parcours récursif d'un objet GeoJSON
+fn geojson_anneaux(json: &Value, anneaux: &mut Vec<Vec<(f64, f64)>>) {
+    match value_to_string(json["type"].to_owned()).as_deref() {
+        Some("FeatureCollection") => {
+            if let Some(features) = json["features"].as_array() {
+                for feature in features {
+                    geojson_anneaux(feature, anneaux);
+                }
+            }
+        }
+        Some("Feature") => geojson_anneaux(&json["geometry"], anneaux),
+        Some("GeometryCollection") => {
+            if let Some(geometries) = json["geometries"].as_array() {
+                for geometrie in geometries {
+                    geojson_anneaux(geometrie, anneaux);
+                }
+            }
+        }
+        Some("Polygon") => {
+            if let Some(polygone) = json["coordinates"].as_array() {
+                geojson_polygone(polygone, anneaux);
+            }
+        }
+        Some("MultiPolygon") => {
+            if let Some(polygones) = json["coordinates"].as_array() {
+                for polygone in polygones {
+                    if let Some(p) = polygone.as_array() {
+                        geojson_polygone(p, anneaux);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Anneaux d'un polygone : les positions GeoJSON sont en [longitude, latitude]
+fn geojson_polygone(polygone: &[Value], anneaux: &mut Vec<Vec<(f64, f64)>>) {
+    for anneau in polygone {
+        let mut points = vec![];
+        if let Some(positions) = anneau.as_array() {
+            for position in positions {
+                // as_f64 accepte aussi les coordonnées entières
+                let lon = position[0].as_f64();
+                let lat = position[1].as_f64();
+                if let (Some(lat), Some(lon)) = (lat, lon) {
+                    points.push((lat, lon))
+                }
+            }
+        }
+        if points.len() > 2 {
+            anneaux.push(points);
+        }
+    }
+}
+
+/// Taille des icons dessinés dans les canvas (de 0 à 160 sur chaque axe)
+const TAILLE_ICON: f64 = 160.0;
+
+/// Distance (dans le repère des icons) entre deux points pris sur un tracé SVG
+const PAS_ECHANTILLON: f64 = 3.0;
+
+/// Nombre maximum de points pris sur une courbe ou un arc, pour qu'un
+/// tracé démesuré ne bloque pas la lecture du fichier
+const ECHANTILLONS_MAX: f64 = 1000.0;
+
+#[allow(non_snake_case)]
+/// Lecture d'un icon au format SVG : les éléments polyline, polygon et path
+/// sont échantillonnés en points, ramenés dans le repère des icons (0 à 160,
+/// l'axe des ordonnées vers le haut) en conservant les proportions du viewBox.
+/// Les transformations (attribut transform) ne sont pas prises en compte.
+pub fn SVGToIcon(string: String) -> Result<data::IconSVG, MeteoErreurs> {
+    let balises = svg_balises(&string);
+    let svg = match balises.iter().find(|(nom, _)| nom == "svg") {
+        Some((_, balise)) => balise,
+        None => return Err(MeteoErreurs::StringParseErreur),
+    };
+
+    // Cadre du dessin : le viewBox, ou à défaut la largeur et la hauteur
+    let cadre: Vec<f64> = match svg_attribut(svg, "viewBox") {
+        Some(v) => svg_nombres(&v),
+        None => {
+            let largeur = svg_attribut(svg, "width").map(|l| svg_nombres(&l));
+            let hauteur = svg_attribut(svg, "height").map(|h| svg_nombres(&h));
+            match (largeur.as_deref(), hauteur.as_deref()) {
+                (Some([l, ..]), Some([h, ..])) => vec![0.0, 0.0, *l, *h],
+                _ => vec![],
+            }
+        }
+    };
+    let (min_x, min_y, largeur, hauteur) = match cadre.as_slice() {
+        [x, y, l, h] if *l > 0.0 && *h > 0.0 => (*x, *y, *l, *h),
+        _ => return Err(MeteoErreurs::StringParseErreur),
+    };
+    let echelle = TAILLE_ICON / largeur.max(hauteur);
+    let decalage_x = (TAILLE_ICON - largeur * echelle) / 2.0;
+    let decalage_y = (TAILLE_ICON - hauteur * echelle) / 2.0;
+    let pas = PAS_ECHANTILLON / echelle;
+
+    let mut traces = vec![];
+    for (element, balise) in &balises {
+        let points = match element.as_str() {
+            "polyline" | "polygon" => {
+                let nombres = svg_nombres(&svg_attribut(balise, "points").unwrap_or_default());
+                let mut sommets: Vec<(f64, f64)> =
+                    nombres.chunks_exact(2).map(|c| (c[0], c[1])).collect();
+                if element == "polygon" && !sommets.is_empty() {
+                    sommets.push(sommets[0]);
+                }
+                echantillonner_ligne_brisee(&sommets, pas)
+            }
+            "path" => svg_chemin(&svg_attribut(balise, "d").unwrap_or_default(), pas),
+            _ => continue,
+        };
+        if points.is_empty() {
+            continue;
+        }
+        traces.push(data::TraceSVG {
+            couleur: svg_couleur(balise),
+            points: points
+                .into_iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .map(|(x, y)| {
+                    (
+                        (x - min_x) * echelle + decalage_x,
+                        TAILLE_ICON - ((y - min_y) * echelle + decalage_y),
+                    )
+                })
+                .collect(),
+        });
+    }
+
+    if traces.is_empty() {
+        return Err(MeteoErreurs::StringParseErreur);
+    }
+    Ok(data::IconSVG { traces })
+}
+
+/// Balises ouvrantes d'un document SVG, dans l'ordre : (nom, contenu)
+fn svg_balises(string: &str) -> Vec<(String, String)> {
+    let mut balises = vec![];
+    let mut reste = string;
+    while let Some(debut) = reste.find('<') {
+        reste = &reste[debut + 1..];
+        let fin = match reste.find('>') {
+            Some(f) => f,
+            None => break,
+        };
+        let balise = &reste[..fin];
+        let nom: String = balise
+            .chars()
+            .take_while(|c| !c.is_whitespace() && *c != '/')
+            .collect();
+        balises.push((nom, balise.to_string()));
+        reste = &reste[fin + 1..];
+    }
+    balises
+}
+
+/// Valeur d'un attribut d'une balise (entre guillemets simples ou doubles)
+fn svg_attribut(balise: &str, nom: &str) -> Option<String> {
+    let motif = format!("{}=", nom);
+    let mut reste = balise;
+    while let Some(position) = reste.find(&motif) {
+        let avant = reste[..position].chars().last();
+        let apres = &reste[position + motif.len()..];
+        if avant.map(|c| c.is_whitespace()).unwrap_or(false) {
+            let guillemet = apres.chars().next()?;
+            if guillemet == '"' || guillemet == '\'' {
+                let valeur = &apres[1..];
+                return valeur.find(guillemet).map(|fin| valeur[..fin].to_string());
+            }
+        }
+        reste = apres;
+    }
+    None
+}
+
+/// Couleur du trait d'un élément (attribut stroke, propriété stroke de
+/// l'attribut style), à défaut celle du remplissage
+fn svg_couleur(balise: &str) -> Option<String> {
+    let style = svg_attribut(balise, "style").unwrap_or_default();
+    let propriete = |nom: &str| {
+        style
+            .split(';')
+            .filter_map(|p| p.split_once(':'))
+            .find(|(cle, _)| cle.trim() == nom)
+            .map(|(_, valeur)| valeur.trim().to_string())
+    };
+    vec![
+        svg_attribut(balise, "stroke"),
+        propriete("stroke"),
+        svg_attribut(balise, "fill"),
+        propriete("fill"),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|c| c != "none")
+}
+
+/// Nombres d'une liste SVG, séparés par des espaces, des virgules, ou
+/// collés ("1-2" ou "0.5.5" donnent deux nombres)
+fn svg_nombres(string: &str) -> Vec<f64> {
+    svg_jetons(string)
+        .into_iter()
+        .filter_map(|j| match j {
+            JetonSVG::Nombre(n) => Some(n),
+            JetonSVG::Commande(_) => None,
+        })
+        .collect()
+}
+
+/// Jeton de l'attribut "d" d'un chemin SVG
+enum JetonSVG {
+    Commande(char),
+    Nombre(f64),
+}
+
+fn svg_jetons(string: &str) -> Vec<JetonSVG> {
+    let caracteres: Vec<char> = string.chars().collect();
+    let mut jetons = vec![];
+    let mut i = 0;
+    while i < caracteres.len() {
+        let c = caracteres[i];
+        if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' {
+            let debut = i;
+            let mut point = c == '.';
+            i += 1;
+            while i < caracteres.len() {
+                let d = caracteres[i];
+                if d.is_ascii_digit() {
+                    i += 1;
+                } else if d == '.' && !point {
+                    point = true;
+                    i += 1;
+                } else if (d == 'e' || d == 'E')
+                    && caracteres
+                        .get(i + 1)
+                        .map(|s| s.is_ascii_digit() || *s == '-' || *s == '+')
+                        .unwrap_or(false)
+                {
+                    i += 2;
+                } else {
+                    break;
+                }
+            }
+            let texte: String = caracteres[debut..i].iter().collect();
+            if let Ok(n) = texte.parse::<f64>() {
+                jetons.push(JetonSVG::Nombre(n))
+            }
+        } else {
+            if c.is_ascii_alphabetic() {
+                jetons.push(JetonSVG::Commande(c));
+            }
+            i += 1;
+        }
+    }
+    jetons
+}
+
+/// Points espacés d'environ "pas" le long d'une ligne brisée
+fn echantillonner_ligne_brisee(sommets: &[(f64, f64)], pas: f64) -> Vec<(f64, f64)> {
+    let mut points = vec![];
+    if let Some(premier) = sommets.first() {
+        points.push(*premier);
+    }
+    for segment in sommets.windows(2) {
+        echantillonner_courbe(&mut points, pas, segment);
+    }
+    points
+}
+
+/// Nombre de points à prendre sur une longueur : au moins un, au plus
+/// ECHANTILLONS_MAX, et un seul si la longueur n'est pas un nombre fini
+fn nombre_echantillons(longueur: f64, pas: f64) -> usize {
+    let nombre = (longueur / pas).ceil();
+    if !nombre.is_finite() {
+        return 1;
+    }
+    nombre.clamp(1.0, ECHANTILLONS_MAX) as usize
+}
+
+/// Courbe de Bézier (segment, quadratique ou cubique selon le nombre de
+/// points de contrôle) échantillonnée à partir de son premier point, exclu
+fn echantillonner_courbe(points: &mut Vec<(f64, f64)>, pas: f64, controle: &[(f64, f64)]) {
+    let longueur: f64 = controle
+        .windows(2)
+        .map(|s| ((s[1].0 - s[0].0).powi(2) + (s[1].1 - s[0].1).powi(2)).sqrt())
+        .sum();
+    let nombre = nombre_echantillons(longueur, pas);
+    for i in 1..=nombre {
+        let t = i as f64 / nombre as f64;
+        // Algorithme de De Casteljau
+        let mut courant = controle.to_vec();
+        while courant.len() > 1 {
+            courant = courant
+                .windows(2)
+                .map(|s| {
+                    (
+                        s[0].0 + (s[1].0 - s[0].0) * t,
+                        s[0].1 + (s[1].1 - s[0].1) * t,
+                    )
+                })
+                .collect();
+        }
+        points.push(courant[0]);
+    }
+}
+
+/// Arc elliptique SVG (commande A), converti en centre et angles
+/// (cf. https://www.w3.org/TR/SVG/implnote.html#ArcConversionEndpointToCenter)
+fn echantillonner_arc(
+    points: &mut Vec<(f64, f64)>,
+    pas: f64,
+    depart: (f64, f64),
+    parametres: &[f64],
+    arrivee: (f64, f64),
+) {
+    // Un arc dont les deux extrémités sont confondues n'est pas dessiné
+    if depart == arrivee {
+        return;
+    }
+    let (mut rx, mut ry) = (parametres[0].abs(), parametres[1].abs());
+    if rx == 0.0 || ry == 0.0 {
+        echantillonner_courbe(points, pas, &[depart, arrivee]);
+        return;
+    }
+    let phi = parametres[2].to_radians();
+    let (grand_arc, sens_positif) = (parametres[3] != 0.0, parametres[4] != 0.0);
+    let (cos_phi, sin_phi) = (phi.cos(), phi.sin());
+
+    let dx = (depart.0 - arrivee.0) / 2.0;
+    let dy = (depart.1 - arrivee.1) / 2.0;
+    let x1 = cos_phi * dx + sin_phi * dy;
+    let y1 = -sin_phi * dx + cos_phi * dy;
+
+    // Rayons trop petits pour relier les deux points : on les agrandit
+    let lambda = (x1 / rx).powi(2) + (y1 / ry).powi(2);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerateur = (rx * ry).powi(2) - (rx * y1).powi(2) - (ry * x1).powi(2);
+    let denominateur = (rx * y1).powi(2) + (ry * x1).powi(2);
+    let mut coefficient = (numerateur / denominateur).max(0.0).sqrt();
+    if grand_arc == sens_positif {
+        coefficient = -coefficient;
+    }
+    let cx1 = coefficient * rx * y1 / ry;
+    let cy1 = -coefficient * ry * x1 / rx;
+    let cx = cos_phi * cx1 - sin_phi * cy1 + (depart.0 + arrivee.0) / 2.0;
+    let cy = sin_phi * cx1 + cos_phi * cy1 + (depart.1 + arrivee.1) / 2.0;
+
+    let angle = |ux: f64, uy: f64| uy.atan2(ux);
+    let theta1 = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry) - theta1;
+    if sens_positif && delta < 0.0 {
+        delta += 2.0 * std::f64::consts::PI;
+    } else if !sens_positif && delta > 0.0 {
+        delta -= 2.0 * std::f64::consts::PI;
+    }
+
+    let nombre = nombre_echantillons(delta.abs() * rx.max(ry), pas);
+    for i in 1..=nombre {
+        let theta = theta1 + delta * i as f64 / nombre as f64;
+        let (x, y) = (rx * theta.cos(), ry * theta.sin());
+        points.push((
+            cos_phi * x - sin_phi * y + cx,
+            sin_phi * x + cos_phi * y + cy,
+        ));
+    }
+}
+
+/// Points d'un chemin SVG (attribut "d") : commandes M, L, H, V, C, S, Q,
+/// T, A et Z, en coordonnées absolues (majuscules) ou relatives (minuscules)
+fn svg_chemin(d: &str, pas: f64) -> Vec<(f64, f64)> {
+    let mut points = vec![];
+    let mut commande = 'M';
+    let mut position = (0.0, 0.0);
+    let mut debut_sous_chemin = (0.0, 0.0);
+    // Dernier point de contrôle, pour les commandes S et T
+    let mut dernier_controle: Option<(char, (f64, f64))> = None;
+
+    let jetons = svg_jetons(d);
+    let mut i = 0;
+    while i < jetons.len() {
+        if let JetonSVG::Commande(c) = jetons[i] {
+            commande = c;
+            i += 1;
+            if c == 'Z' || c == 'z' {
+                echantillonner_courbe(&mut points, pas, &[position, debut_sous_chemin]);
+                position = debut_sous_chemin;
+                dernier_controle = None;
+            }
+            continue;
+        }
+
+        let nombre_parametres = match commande.to_ascii_uppercase() {
+            'M' | 'L' | 'T' => 2,
+            'H' | 'V' => 1,
+            'S' | 'Q' => 4,
+            'C' => 6,
+            'A' => 7,
+            _ => return points,
+        };
+        let mut parametres = vec![];
+        while parametres.len() < nombre_parametres {
+            match jetons.get(i) {
+                Some(JetonSVG::Nombre(n)) => parametres.push(*n),
+                _ => return points,
+            }
+            i += 1;
+        }
+
+        let relatif = commande.is_ascii_lowercase();
+        let point = |x: f64, y: f64| {
+            if relatif {
+                (position.0 + x, position.1 + y)
+            } else {
+                (x, y)
+            }
+        };
+        let reflet = |type_courbe: char| match dernier_controle {
+            Some((t, (x, y))) if t == type_courbe => (2.0 * position.0 - x, 2.0 * position.1 - y),
+            _ => position,
+        };
+
+        let mut controle = None;
+        let arrivee = match commande.to_ascii_uppercase() {
+            'M' => {
+                let p = point(parametres[0], parametres[1]);
+                points.push(p);
+                debut_sous_chemin = p;
+                // Les paires suivantes d'un M sont des L
+                commande = if relatif { 'l' } else { 'L' };
+                p
+            }
+            'L' => {
+                let p = point(parametres[0], parametres[1]);
+                echantillonner_courbe(&mut points, pas, &[position, p]);
+                p
+            }
+            'H' => {
+                let x = if relatif {
+                    position.0 + parametres[0]
+                } else {
+                    parametres[0]
+                };
+                echantillonner_courbe(&mut points, pas, &[position, (x, position.1)]);
+                (x, position.1)
+            }
+            'V' => {
+                let y = if relatif {
+                    position.1 + parametres[0]
+                } else {
+                    parametres[0]
+                };
+                echantillonner_courbe(&mut points, pas, &[position, (position.0, y)]);
+                (position.0, y)
+            }
+            'C' | 'S' => {
+                let (c1, c2, p) = if commande.eq_ignore_ascii_case(&'C') {
+                    (
+                        point(parametres[0], parametres[1]),
+                        point(parametres[2], parametres[3]),
+                        point(parametres[4], parametres[5]),
+                    )
+                } else {
+                    (
+                        reflet('C'),
+                        point(parametres[0], parametres[1]),
+                        point(parametres[2], parametres[3]),
+                    )
+                };
+                echantillonner_courbe(&mut points, pas, &[position, c1, c2, p]);
+                controle = Some(('C', c2));
+                p
+            }
+            'Q' | 'T' => {
+                let (c, p) = if commande.eq_ignore_ascii_case(&'Q') {
+                    (
+                        point(parametres[0], parametres[1]),
+                        point(parametres[2], parametres[3]),
+                    )
+                } else {
+                    (reflet('Q'), point(parametres[0], parametres[1]))
+                };
+                echantillonner_courbe(&mut points, pas, &[position, c, p]);
+                controle = Some(('Q', c));
+                p
+            }
+            _ => {
+                let p = point(parametres[5], parametres[6]);
+                echantillonner_arc(&mut points, pas, position, &parametres, p);
+                p
+            }
+        };
+        position = arrivee;
+        dernier_controle = controle;
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lire_icon(contenu: &str) -> data::IconSVG {
+        let svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 10 10\">{}</svg>",
+            contenu
+        );
+        match SVGToIcon(svg) {
+            Ok(icon) => icon,
+            Err(_) => panic!("SVG illisible : {}", contenu),
+        }
+    }
+
+    #[test]
+    fn svg_nombres_colles() {
+        assert_eq!(svg_nombres("1-2"), vec![1.0, -2.0]);
+        assert_eq!(svg_nombres("0.5.5"), vec![0.5, 0.5]);
+        assert_eq!(svg_nombres("3,4 1e1"), vec![3.0, 4.0, 10.0]);
+    }
+
+    #[test]
+    fn polyline_ramenee_au_repere_des_icons() {
+        let icon = lire_icon("<polyline stroke=\"#f00\" points=\"0,0 10,0\"/>");
+        assert_eq!(icon.traces.len(), 1);
+        let trace = &icon.traces[0];
+        assert_eq!(trace.couleur.as_deref(), Some("#f00"));
+        // L'axe des ordonnées est retourné : le haut du SVG est en y = 160
+        assert_eq!(trace.points.first(), Some(&(0.0, TAILLE_ICON)));
+        assert_eq!(trace.points.last(), Some(&(TAILLE_ICON, TAILLE_ICON)));
+    }
+
+    #[test]
+    fn chemin_relatif_et_ferme() {
+        let icon = lire_icon("<path d=\"m 1 1 h 8 v 8 z\"/>");
+        let points = &icon.traces[0].points;
+        assert_eq!(points.first(), points.last());
+    }
+
+    #[test]
+    fn arc_aux_extremites_confondues_ignore() {
+        let icon = lire_icon("<path d=\"M 5 5 A 2 2 0 0 1 5 5\"/>");
+        assert_eq!(icon.traces[0].points.len(), 1);
+    }
+
+    #[test]
+    fn arc_demi_cercle() {
+        let icon = lire_icon("<path d=\"M 0 5 A 5 5 0 0 1 10 5\"/>");
+        let points = &icon.traces[0].points;
+        assert!(points.len() > 2);
+        for (x, y) in points {
+            let distance = ((x - 80.0).powi(2) + (y - 80.0).powi(2)).sqrt();
+            assert!((distance - 80.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn longueurs_demesurees_bornees() {
+        let icon = lire_icon("<path d=\"M 0 0 L 1e300 0\"/>");
+        assert!(icon.traces[0].points.len() <= ECHANTILLONS_MAX as usize + 1);
+
+        // Une longueur infinie ne donne que des points finis
+        let icon = lire_icon("<path d=\"M 0 0 L 1 1 L 1e999 0\"/>");
+        assert!(icon.traces[0]
+            .points
+            .iter()
+            .all(|(x, y)| x.is_finite() && y.is_finite()));
+    }
+
+    #[test]
+    fn svg_sans_cadre_ni_trace_refuse() {
+        assert!(SVGToIcon(String::from("<g></g>")).is_err());
+        assert!(SVGToIcon(String::from("<svg><polyline points=\"0,0 1,1\"/></svg>")).is_err());
+        assert!(SVGToIcon(String::from("<svg viewBox=\"0 0 10 10\"></svg>")).is_err());
+    }
+
+    #[test]
+    fn contours_polygone_et_multipolygone() {
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "geometry": {"type": "Polygon",
+                    "coordinates": [[[2, 48], [3, 48], [3, 49], [2, 48]]]}},
+                {"type": "Feature", "geometry": {"type": "MultiPolygon",
+                    "coordinates": [[[[8.5, 41.5], [9.5, 41.5], [9, 43], [8.5, 41.5]]],
+                                    [[[0, 0], [1, 1]]]]}},
+                {"type": "Feature", "geometry": {"type": "Point", "coordinates": [2, 48]}}
+            ]
+        }"#;
+        let contours = match JSONToContours(String::from("test"), String::from(geojson)) {
+            Ok(c) => c,
+            Err(_) => panic!("GeoJSON illisible"),
+        };
+        assert_eq!(contours.nom, "test");
+        // Les anneaux de moins de trois points et les autres géométries sont ignorés
+        assert_eq!(contours.anneaux.len(), 2);
+        // Les positions [longitude, latitude] deviennent (latitude, longitude)
+        assert_eq!(contours.anneaux[0][1], (48.0, 3.0));
+        assert_eq!(contours.anneaux[1][0], (41.5, 8.5));
+    }
+
+    #[test]
+    fn contours_illisibles() {
+        let sans_polygone = r#"{"type": "Point", "coordinates": [2, 48]}"#;
+        assert!(JSONToContours(String::from("a"), String::from(sans_polygone)).is_err());
+        assert!(JSONToContours(String::from("b"), String::from("{pas du json")).is_err());
+    }
+}
@@ -0,0 +1,513 @@
+use crate::ascii_icons;
+use crate::data::{Contours, Place};
+use crate::interpolation;
+
+/// Limites du canvas de la carte (en x comme en y)
+pub const LIMITES_CARTE: [f64; 2] = [0.0, 170.0];
+
+/// Part du canvas occupée par le tracé principal
+const MARGE_CARTE: f64 = 0.95;
+
+/// Emprise de la France métropolitaine, utilisée si aucun tracé
+/// principal n'a pu être lu : [lat_min, lat_max, lon_min, lon_max]
+const EMPRISE_FRANCE: [f64; 4] = [41.33, 51.09, -5.14, 9.56];
+
+#[derive(Debug, Clone, Copy)]
+/// Projection équirectangulaire avec correction cos(lat) : les degrés de
+/// longitude sont "rétrécis" selon le parallèle de référence, pour que
+/// la carte ne paraisse pas étirée horizontalement.
+///
+/// x = echelle * cos(parallele) * (lon - lon_origine)
+/// y = echelle * (lat - lat_origine)
+pub struct Projection {
+    pub lat_origine: f64,
+    pub lon_origine: f64,
+    pub parallele: f64,
+    pub echelle: f64,
+}
+
+impl Projection {
+    /// Projection qui fait tenir l'emprise [lat_min, lat_max, lon_min, lon_max]
+    /// dans le cadre [x_min, y_min, x_max, y_max] du canvas : le centre de
+    /// l'emprise est placé au centre du cadre et elle n'en occupe que la
+    /// part `marge`. Le parallèle de référence est celui du milieu de l'emprise.
+    pub fn ajustee(emprise: [f64; 4], cadre: [f64; 4], marge: f64) -> Self {
+        let [lat_min, lat_max, lon_min, lon_max] = emprise;
+        let parallele = (lat_min + lat_max) / 2.0;
+        let cos = parallele.to_radians().cos();
+        // Une emprise réduite à un point ou à une ligne garde une échelle finie
+        let largeur = (cos * (lon_max - lon_min)).max(1e-6);
+        let hauteur = (lat_max - lat_min).max(1e-6);
+        let echelle =
+            ((cadre[2] - cadre[0]) / largeur).min((cadre[3] - cadre[1]) / hauteur) * marge;
+
+        Projection {
+            lat_origine: (lat_min + lat_max) / 2.0 - (cadre[1] + cadre[3]) / 2.0 / echelle,
+            lon_origine: (lon_min + lon_max) / 2.0 - (cadre[0] + cadre[2]) / 2.0 / (echelle * cos),
+            parallele,
+            echelle,
+        }
+    }
+
+    fn echelle_x(&self) -> f64 {
+        self.echelle * self.parallele.to_radians().cos()
+    }
+
+    /// Latitude et longitude vers coordonnées du canvas
+    pub fn projeter(&self, lat: f64, lon: f64) -> (f64, f64) {
+        let x = self.echelle_x() * (lon - self.lon_origine);
+        let y = self.echelle * (lat - self.lat_origine);
+        (x, y)
+    }
+
+    /// Coordonnées du canvas vers latitude et longitude (transformation inverse)
+    pub fn inverser(&self, x: f64, y: f64) -> (f64, f64) {
+        let lat = y / self.echelle + self.lat_origine;
+        let lon = x / self.echelle_x() + self.lon_origine;
+        (lat, lon)
+    }
+
+    /// Le point est-il visible sur le canvas ?
+    pub fn contient(&self, x: f64, y: f64) -> bool {
+        let limites = LIMITES_CARTE[0]..=LIMITES_CARTE[1];
+        limites.contains(&x) && limites.contains(&y)
+    }
+}
+
+/// Encart de la carte : un cadre du canvas dans lequel un territoire
+/// (Corse, départements d'outre-mer) est dessiné avec sa propre projection
+#[derive(Debug, Clone)]
+pub struct Encart {
+    pub nom: &'static str,
+    /// Cadre de l'encart sur le canvas : [x_min, y_min, x_max, y_max]
+    pub cadre: [f64; 4],
+    /// Emprise géographique du territoire : [lat_min, lat_max] et [lon_min, lon_max]
+    pub lat: [f64; 2],
+    pub lon: [f64; 2],
+    pub projection: Projection,
+    /// Le territoire est-il dessiné à l'échelle de la métropole ?
+    pub metropole: bool,
+    /// Contour du territoire, en coordonnées du canvas
+    pub contour: Vec<(f64, f64)>,
+}
+
+impl Encart {
+    /// Encart à l'échelle de la métropole : la Corse est déjà à sa place
+    /// sur la carte et son contour fait partie de celui de la France
+    fn metropolitain(nom: &'static str, cadre: [f64; 4], projection: Projection) -> Self {
+        let (lat_min, lon_min) = projection.inverser(cadre[0], cadre[1]);
+        let (lat_max, lon_max) = projection.inverser(cadre[2], cadre[3]);
+        Encart {
+            nom,
+            cadre,
+            lat: [lat_min, lat_max],
+            lon: [lon_min, lon_max],
+            projection,
+            metropole: true,
+            contour: vec![],
+        }
+    }
+
+    /// Encart dont la projection est calculée pour que le contour
+    /// (en latitude, longitude) remplisse le cadre, avec une petite marge
+    fn outremer(nom: &'static str, cadre: [f64; 4], contour: Vec<(f64, f64)>) -> Self {
+        let emprise = emprise(std::slice::from_ref(&contour)).unwrap_or([0.0, 0.0, 0.0, 0.0]);
+        let projection = Projection::ajustee(emprise, cadre, MARGE_ENCART);
+        let contour = contour
+            .iter()
+            .map(|(lat, lon)| projection.projeter(*lat, *lon))
+            .collect();
+
+        // L'emprise couvre tout le cadre, pour y placer aussi les îlots voisins
+        let (lat_bas, lon_gauche) = projection.inverser(cadre[0], cadre[1]);
+        let (lat_haut, lon_droite) = projection.inverser(cadre[2], cadre[3]);
+        Encart {
+            nom,
+            cadre,
+            lat: [lat_bas, lat_haut],
+            lon: [lon_gauche, lon_droite],
+            projection,
+            metropole: false,
+            contour,
+        }
+    }
+
+    /// Le lieu (lat, lon) fait-il partie du territoire de l'encart ?
+    pub fn contient_lieu(&self, lat: f64, lon: f64) -> bool {
+        (self.lat[0]..=self.lat[1]).contains(&lat) && (self.lon[0]..=self.lon[1]).contains(&lon)
+    }
+
+    /// Le point (x, y) du canvas est-il dans le cadre de l'encart ?
+    pub fn contient(&self, x: f64, y: f64) -> bool {
+        (self.cadre[0]..=self.cadre[2]).contains(&x) && (self.cadre[1]..=self.cadre[3]).contains(&y)
+    }
+}
+
+/// Part du cadre occupée par le territoire dans un encart d'outre-mer
+const MARGE_ENCART: f64 = 0.8;
+
+/// Emprise [lat_min, lat_max, lon_min, lon_max] des anneaux (en latitude,
+/// longitude), s'ils ont au moins un point
+fn emprise(anneaux: &[Vec<(f64, f64)>]) -> Option<[f64; 4]> {
+    let points: Vec<&(f64, f64)> = anneaux
+        .iter()
+        .flatten()
+        .filter(|(lat, lon)| lat.is_finite() && lon.is_finite())
+        .collect();
+    if points.is_empty() {
+        return None;
+    }
+    let mut emprise = [
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for (lat, lon) in points {
+        emprise[0] = emprise[0].min(*lat);
+        emprise[1] = emprise[1].max(*lat);
+        emprise[2] = emprise[2].min(*lon);
+        emprise[3] = emprise[3].max(*lon);
+    }
+    Some(emprise)
+}
+
+/// Carte complète : le tracé principal, ses encarts et les contours à dessiner.
+/// La projection principale est calculée d'après l'emprise du tracé principal.
+/// Un lieu situé dans l'emprise d'un encart est placé avec la projection
+/// de cet encart.
+#[derive(Debug, Clone)]
+pub struct Carte {
+    pub principale: Projection,
+    pub encarts: Vec<Encart>,
+    /// Anneaux du tracé principal, en coordonnées du canvas
+    pub contours: Vec<Vec<(f64, f64)>>,
+    /// Anneaux des autres fichiers GeoJSON (pays voisins, départements...)
+    pub contours_secondaires: Vec<Vec<(f64, f64)>>,
+}
+
+impl Carte {
+    /// Carte dont le tracé principal est le fichier de contours `nom_principal`
+    /// ("france" s'il n'existe pas). La projection fait tenir ce tracé dans
+    /// le canvas. Pour la France, les encarts d'outre-mer sont empilés
+    /// dans l'Atlantique et la Corse garde sa place réelle.
+    pub fn new(contours: &[Contours], nom_principal: &str) -> Self {
+        let nom_principal = if contours.iter().any(|c| c.nom == nom_principal) {
+            nom_principal
+        } else {
+            "france"
+        };
+        let anneaux_principaux: Vec<Vec<(f64, f64)>> = contours
+            .iter()
+            .filter(|c| c.nom == nom_principal)
+            .flat_map(|c| c.anneaux.clone())
+            .collect();
+        let cadre = [
+            LIMITES_CARTE[0],
+            LIMITES_CARTE[0],
+            LIMITES_CARTE[1],
+            LIMITES_CARTE[1],
+        ];
+        let principale = Projection::ajustee(
+            emprise(&anneaux_principaux).unwrap_or(EMPRISE_FRANCE),
+            cadre,
+            MARGE_CARTE,
+        );
+
+        let mut carte = Carte {
+            principale,
+            contours: vec![],
+            contours_secondaires: vec![],
+            encarts: match nom_principal {
+                "france" => Carte::encarts_france(principale),
+                _ => vec![],
+            },
+        };
+
+        for c in contours {
+            let anneaux: Vec<Vec<(f64, f64)>> = c
+                .anneaux
+                .iter()
+                .map(|anneau| {
+                    anneau
+                        .iter()
+                        .map(|(lat, lon)| carte.projeter(*lat, *lon))
+                        .collect()
+                })
+                .collect();
+            if c.nom == nom_principal {
+                carte.contours.extend(anneaux);
+            } else {
+                carte.contours_secondaires.extend(anneaux);
+            }
+        }
+        carte
+    }
+
+    /// Encarts de la carte de France : la Corse (à l'échelle de la métropole)
+    /// dans le coin sud-est, les départements d'outre-mer à l'ouest
+    fn encarts_france(principale: Projection) -> Vec<Encart> {
+        vec![
+            Encart::metropolitain("Corse", [150.0, 3.0, 169.0, 35.0], principale),
+            Encart::outremer(
+                "Guadeloupe",
+                [2.0, 78.0, 32.0, 94.0],
+                ascii_icons::get_outremer_coords("Guadeloupe"),
+            ),
+            Encart::outremer(
+                "Martinique",
+                [2.0, 59.0, 32.0, 75.0],
+                ascii_icons::get_outremer_coords("Martinique"),
+            ),
+            Encart::outremer(
+                "Guyane",
+                [2.0, 40.0, 32.0, 56.0],
+                ascii_icons::get_outremer_coords("Guyane"),
+            ),
+            Encart::outremer(
+                "La Réunion",
+                [2.0, 21.0, 32.0, 37.0],
+                ascii_icons::get_outremer_coords("La Réunion"),
+            ),
+            Encart::outremer(
+                "Mayotte",
+                [2.0, 2.0, 32.0, 18.0],
+                ascii_icons::get_outremer_coords("Mayotte"),
+            ),
+        ]
+    }
+
+    /// Le point (x, y) du canvas est-il à l'intérieur du tracé principal ?
+    /// (règle pair-impair, pour tenir compte des trous des polygones)
+    pub fn dans_contours(&self, x: f64, y: f64) -> bool {
+        let traverses = self
+            .contours
+            .iter()
+            .filter(|anneau| interpolation::dans_polygone(anneau, x, y))
+            .count();
+        traverses % 2 == 1
+    }
+
+    /// Encart dont le territoire contient le lieu (lat, lon)
+    fn encart_du_lieu(&self, lat: f64, lon: f64) -> Option<&Encart> {
+        self.encarts.iter().find(|e| e.contient_lieu(lat, lon))
+    }
+
+    /// Encart dont le cadre contient le point (x, y) du canvas
+    pub fn encart(&self, x: f64, y: f64) -> Option<&Encart> {
+        self.encarts.iter().find(|e| e.contient(x, y))
+    }
+
+    /// Le point (x, y) du canvas est-il dans un encart d'outre-mer ?
+    pub fn outremer(&self, x: f64, y: f64) -> bool {
+        match self.encart(x, y) {
+            Some(encart) => !encart.metropole,
+            None => false,
+        }
+    }
+
+    /// Latitude et longitude vers coordonnées du canvas
+    pub fn projeter(&self, lat: f64, lon: f64) -> (f64, f64) {
+        match self.encart_du_lieu(lat, lon) {
+            Some(encart) => encart.projection.projeter(lat, lon),
+            None => self.principale.projeter(lat, lon),
+        }
+    }
+
+    /// Coordonnées du canvas vers latitude et longitude
+    pub fn inverser(&self, x: f64, y: f64) -> (f64, f64) {
+        match self.encart(x, y) {
+            Some(encart) => encart.projection.inverser(x, y),
+            None => self.principale.inverser(x, y),
+        }
+    }
+
+    /// Position d'un lieu sur le canvas, s'il a des coordonnées
+    pub fn projeter_place(&self, place: &Place) -> Option<(f64, f64)> {
+        match (place.lat, place.lon) {
+            (Some(lat), Some(lon)) => Some(self.projeter(lat, lon)),
+            _ => None,
+        }
+    }
+
+    /// Lieu (sans nom) correspondant à un point du canvas
+    pub fn place(&self, x: f64, y: f64) -> Place {
+        let (lat, lon) = self.inverser(x, y);
+        let mut place = Place::new();
+        place.lat = Some(lat);
+        place.lon = Some(lon);
+        place
+    }
+
+    /// Le point est-il visible sur le canvas ?
+    pub fn contient(&self, x: f64, y: f64) -> bool {
+        self.principale.contient(x, y)
+    }
+}
+
+/// Facteur de zoom maximum de la carte
+pub const ZOOM_MAX: f64 = 8.0;
+
+/// Partie du canvas affichée : un centre et un facteur de zoom.
+/// Au zoom 1, on voit tout le canvas (LIMITES_CARTE).
+#[derive(Debug, Clone, Copy)]
+pub struct Vue {
+    pub centre: (f64, f64),
+    pub zoom: f64,
+}
+
+impl Vue {
+    /// Vue de tout le canvas, sans zoom
+    pub fn entiere() -> Self {
+        let milieu = (LIMITES_CARTE[0] + LIMITES_CARTE[1]) / 2.0;
+        Vue {
+            centre: (milieu, milieu),
+            zoom: 1.0,
+        }
+    }
+
+    /// Moitié de la largeur (et de la hauteur) visible, en unités du canvas
+    pub fn demi_etendue(&self) -> f64 {
+        (LIMITES_CARTE[1] - LIMITES_CARTE[0]) / 2.0 / self.zoom
+    }
+
+    pub fn x_bounds(&self) -> [f64; 2] {
+        let d = self.demi_etendue();
+        [self.centre.0 - d, self.centre.0 + d]
+    }
+
+    pub fn y_bounds(&self) -> [f64; 2] {
+        let d = self.demi_etendue();
+        [self.centre.1 - d, self.centre.1 + d]
+    }
+
+    /// Le point (x, y) du canvas est-il affiché ?
+    pub fn contient(&self, x: f64, y: f64) -> bool {
+        let [gauche, droite] = self.x_bounds();
+        let [bas, haut] = self.y_bounds();
+        (gauche..=droite).contains(&x) && (bas..=haut).contains(&y)
+    }
+
+    /// Multiplie le zoom par le facteur ; en zoomant, la vue se centre sur le point visé
+    pub fn zoomer(&mut self, facteur: f64, vers: (f64, f64)) {
+        self.zoom = (self.zoom * facteur).clamp(1.0, ZOOM_MAX);
+        if facteur > 1.0 {
+            self.centre = vers;
+        }
+        self.recadrer();
+    }
+
+    /// Déplace la vue d'un quart de sa largeur dans la direction (dx, dy)
+    pub fn deplacer(&mut self, dx: f64, dy: f64) {
+        let pas = self.demi_etendue() / 2.0;
+        self.centre = (self.centre.0 + dx * pas, self.centre.1 + dy * pas);
+        self.recadrer();
+    }
+
+    pub fn centrer(&mut self, x: f64, y: f64) {
+        self.centre = (x, y);
+        self.recadrer();
+    }
+
+    /// La vue ne sort jamais du canvas
+    fn recadrer(&mut self) {
+        let d = self.demi_etendue();
+        self.centre = (
+            self.centre
+                .0
+                .clamp(LIMITES_CARTE[0] + d, LIMITES_CARTE[1] - d),
+            self.centre
+                .1
+                .clamp(LIMITES_CARTE[0] + d, LIMITES_CARTE[1] - d),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projection() -> Projection {
+        Projection {
+            lat_origine: 41.0,
+            lon_origine: -5.0,
+            parallele: 60.0,
+            echelle: 10.0,
+        }
+    }
+
+    #[test]
+    fn projeter_puis_inverser() {
+        let projection = projection();
+        for (lat, lon) in [(48.8592, 2.3417), (43.29667, 5.37639), (41.0, -5.0)] {
+            let (x, y) = projection.projeter(lat, lon);
+            let (lat2, lon2) = projection.inverser(x, y);
+            assert!((lat - lat2).abs() < 1e-9);
+            assert!((lon - lon2).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn correction_cos_lat() {
+        // Au 60e parallèle, un degré de longitude vaut la moitié d'un degré de latitude
+        let projection = projection();
+        assert_eq!(projection.projeter(41.0, -5.0), (0.0, 0.0));
+        let (x, y) = projection.projeter(42.0, -4.0);
+        assert!((x - 5.0).abs() < 1e-9);
+        assert!((y - 10.0).abs() < 1e-9);
+    }
+
+    fn contours(nom: &str, anneau: Vec<(f64, f64)>) -> Contours {
+        Contours {
+            nom: String::from(nom),
+            anneaux: vec![anneau],
+        }
+    }
+
+    #[test]
+    fn projection_ajustee_au_trace_principal() {
+        // Belgique, à peu près
+        let belgique = contours(
+            "belgique",
+            vec![(51.5, 2.5), (51.5, 6.4), (49.5, 6.4), (49.5, 2.5)],
+        );
+        let carte = Carte::new(&[belgique], "belgique");
+        assert!(carte.encarts.is_empty());
+
+        let (x_min, y_min) = carte.projeter(49.5, 2.5);
+        let (x_max, y_max) = carte.projeter(51.5, 6.4);
+        let limites = LIMITES_CARTE[0]..=LIMITES_CARTE[1];
+        for valeur in &[x_min, y_min, x_max, y_max] {
+            assert!(limites.contains(valeur));
+        }
+        // Le tracé est centré et remplit le canvas dans sa plus grande dimension
+        let milieu = (LIMITES_CARTE[0] + LIMITES_CARTE[1]) / 2.0;
+        assert!(((x_min + x_max) / 2.0 - milieu).abs() < 1e-9);
+        assert!(((y_min + y_max) / 2.0 - milieu).abs() < 1e-9);
+        let etendue = (LIMITES_CARTE[1] - LIMITES_CARTE[0]) * MARGE_CARTE;
+        assert!(((x_max - x_min).max(y_max - y_min) - etendue).abs() < 1e-9);
+    }
+
+    #[test]
+    fn contours_secondaires_avec_la_meme_projection() {
+        let suisse = contours("suisse", vec![(47.8, 6.0), (47.8, 10.5), (45.8, 10.5)]);
+        let voisin = contours("voisin", vec![(47.0, 7.0), (47.0, 8.0), (46.0, 8.0)]);
+        let carte = Carte::new(&[voisin, suisse], "suisse");
+        assert_eq!(carte.contours.len(), 1);
+        assert_eq!(carte.contours_secondaires.len(), 1);
+        assert_eq!(
+            carte.contours_secondaires[0][0],
+            carte.principale.projeter(47.0, 7.0)
+        );
+    }
+
+    #[test]
+    fn encarts_pour_la_france_seulement() {
+        let france = contours("france", vec![(51.0, -5.0), (51.0, 9.5), (41.4, 9.5)]);
+        let carte = Carte::new(std::slice::from_ref(&france), "france");
+        assert!(!carte.encarts.is_empty());
+        // Un tracé principal introuvable : on revient à celui de la France
+        let carte = Carte::new(&[france], "belgique");
+        assert!(!carte.encarts.is_empty());
+    }
+}
@@ -1,7 +1,11 @@
 use confy::ConfyError;
+use directories::ProjectDirs;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
 
 use crate::client::MeteoFranceAPI;
-use crate::data::Place;
+use crate::data::{Affichage, Favoris, Historique, Place, VillesCarte};
 use crate::errors::MeteoErreurs;
 
 /// Lecture de la ville par défaut depuis le fichier de configuration
@@ -21,3 +25,156 @@ pub fn edit_default_place(place: Place) -> Result<(), MeteoErreurs> {
         Err(_) => Err(MeteoErreurs::BadPlaceRequest),
     }
 }
+
+/// Chemin d'un fichier placé dans le même dossier que la configuration
+/// principale (ex : ~/.config/meteo/favoris.toml)
+fn get_config_path(fichier: &str) -> Result<PathBuf, MeteoErreurs> {
+    match ProjectDirs::from("rs", "", "meteo") {
+        Some(dirs) => Ok(dirs.config_dir().join(fichier)),
+        None => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Chemin d'un fichier placé dans le dossier des données de l'application
+/// (ex : ~/.local/share/meteo/historique.toml)
+fn get_data_path(fichier: &str) -> Result<PathBuf, MeteoErreurs> {
+    match ProjectDirs::from("rs", "", "meteo") {
+        Some(dirs) => Ok(dirs.data_dir().join(fichier)),
+        None => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Lecture de la liste des favoris depuis le fichier de configuration
+pub fn get_favoris() -> Result<Favoris, MeteoErreurs> {
+    let path = get_config_path("favoris.toml")?;
+    let cfg: Result<Favoris, ConfyError> = confy::load_path(path);
+    match cfg {
+        Ok(c) => Ok(c),
+        Err(_) => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Ecriture de la liste des favoris dans le fichier de configuration
+pub fn edit_favoris(favoris: Favoris) -> Result<(), MeteoErreurs> {
+    let path = get_config_path("favoris.toml")?;
+    let result = confy::store_path(path, favoris);
+    match result {
+        Ok(e) => Ok(e),
+        Err(_) => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Lecture de l'historique des recherches depuis le dossier des données
+pub fn get_historique() -> Result<Historique, MeteoErreurs> {
+    let path = get_data_path("historique.toml")?;
+    let cfg: Result<Historique, ConfyError> = confy::load_path(path);
+    match cfg {
+        Ok(c) => Ok(c),
+        Err(_) => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Ecriture de l'historique des recherches dans le dossier des données
+pub fn edit_historique(historique: Historique) -> Result<(), MeteoErreurs> {
+    let path = get_data_path("historique.toml")?;
+    let result = confy::store_path(path, historique);
+    match result {
+        Ok(e) => Ok(e),
+        Err(_) => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Lecture des villes affichées sur la carte depuis le fichier de configuration
+pub fn get_villes_carte() -> Result<VillesCarte, MeteoErreurs> {
+    let path = get_config_path("carte.toml")?;
+    let cfg: Result<VillesCarte, ConfyError> = confy::load_path(path);
+    match cfg {
+        Ok(c) => Ok(c),
+        Err(_) => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Lecture des préférences d'affichage depuis le fichier de configuration
+pub fn get_affichage() -> Result<Affichage, MeteoErreurs> {
+    let path = get_config_path("affichage.toml")?;
+    let cfg: Result<Affichage, ConfyError> = confy::load_path(path);
+    match cfg {
+        Ok(c) => Ok(c),
+        Err(_) => Err(MeteoErreurs::BadStorageRequest),
+    }
+}
+
+/// Lecture des fichiers d'un dossier dont l'extension fait partie de la
+/// liste : on renvoie le nom de chaque fichier (sans extension) et son
+/// contenu, triés par nom. Si le dossier n'existe pas, il n'y a aucun fichier.
+fn get_fichiers_dossier(
+    dossier: PathBuf,
+    extensions: &[&str],
+) -> Result<Vec<(String, String)>, MeteoErreurs> {
+    let entrees = match fs::read_dir(dossier) {
+        Ok(e) => e,
+        Err(_) => return Ok(vec![]),
+    };
+
+    let mut fichiers = vec![];
+    for entree in entrees.flatten() {
+        let path = entree.path();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        if !extensions.contains(&extension) {
+            continue;
+        }
+        let nom = path
+            .file_stem()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if let Ok(contenu) = fs::read_to_string(&path) {
+            fichiers.push((nom.to_string(), contenu))
+        }
+    }
+    fichiers.sort();
+    Ok(fichiers)
+}
+
+/// Lecture des fichiers GeoJSON (.geojson ou .json) du dossier "contours"
+/// de la configuration
+pub fn get_fichiers_contours() -> Result<Vec<(String, String)>, MeteoErreurs> {
+    let dossier = get_config_path("contours")?;
+    get_fichiers_dossier(dossier, &["geojson", "json"])
+}
+
+/// Lecture des fichiers SVG d'un pack d'icons (dossier "icones/<pack>"
+/// de la configuration)
+pub fn get_fichiers_pack_icons(pack: &str) -> Result<Vec<(String, String)>, MeteoErreurs> {
+    let dossier = get_config_path("icones")?.join(pack);
+    get_fichiers_dossier(dossier, &["svg"])
+}
+
+/// Ajout d'une ligne datée à un journal du dossier des données
+/// (ex : ~/.local/share/meteo/meteo.log)
+fn journaliser(fichier: &str, message: &str) -> Result<(), MeteoErreurs> {
+    let path = get_data_path(fichier)?;
+    if let Some(dossier) = path.parent() {
+        fs::create_dir_all(dossier).map_err(|_| MeteoErreurs::BadStorageRequest)?;
+    }
+    let mut fichier = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|_| MeteoErreurs::BadStorageRequest)?;
+    let date = chrono::Local::now().format("%Y-%m-%d %H:%M");
+    writeln!(fichier, "{} {}", date, message).map_err(|_| MeteoErreurs::BadStorageRequest)
+}
+
+/// Ajout d'un code de pictogramme inconnu au journal pictogrammes_inconnus.log
+pub fn journaliser_pictogramme_inconnu(code: &str) -> Result<(), MeteoErreurs> {
+    journaliser("pictogrammes_inconnus.log", code)
+}
+
+/// Ajout d'un problème rencontré (fichier illisible...) au journal meteo.log,
+/// l'interface occupant le terminal
+pub fn journaliser_erreur(message: &str) -> Result<(), MeteoErreurs> {
+    journaliser("meteo.log", message)
+}
@@ -2,18 +2,27 @@
     Cf. https://docs.rs/tui/latest/tui/
 */
 
-use crossterm::event::{self, DisableMouseCapture, Event, KeyCode};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton,
+    MouseEvent, MouseEventKind,
+};
 use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::io::{self, Stdout};
+use std::time::{Duration, Instant};
 use tui::backend::CrosstermBackend;
 use tui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use tui::symbols::Marker;
 use tui::text::{Span, Spans, Text};
-use tui::widgets::canvas::{Canvas, Rectangle};
-use tui::widgets::{BarChart, Block, Borders, Paragraph, Tabs, Wrap};
+use tui::widgets::canvas::{Canvas, Context, Line, Points, Rectangle};
+use tui::widgets::{
+    Axis, Block, Borders, Cell, Chart, Clear, Dataset, GraphType, Paragraph, Row, Table,
+    TableState, Tabs, Wrap,
+};
 use tui::Terminal;
 use tui::{
     backend::Backend,
@@ -22,11 +31,18 @@ use tui::{
 };
 use unicode_width::UnicodeWidthStr;
 
-use crate::ascii_icons;
+use crate::ascii_icons::{self, PackIcons};
 use crate::client::MeteoFranceAPI;
-use crate::data::{Place, Prevision};
+use crate::data::{
+    DailyForecast, Favori, Favoris, Forecast, Historique, Place, Prevision, ProbabilityForecast,
+    Recherche, Symboles, VilleCarte, VillesCarte,
+};
 use crate::errors::MeteoErreurs;
-use crate::util::DateHeure::{self, get_dateheure_from_str};
+use crate::interpolation;
+use crate::projection::{Carte, Vue};
+use crate::storage;
+use crate::util::DateHeure::{self, get_dateheure_from_str, get_dateheure_locale, get_nom_jour};
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
 
 #[derive(Clone)]
 /// Structure qui permettra de garder en mémoire
@@ -36,180 +52,421 @@ use crate::util::DateHeure::{self, get_dateheure_from_str};
 /// On aura besoin de leurs prévisions météo, mais aussi de leur coordonnées
 /// sur la carte, de leur position relatives aux autres villes etc.
 struct FrancePrevision {
-    code: i32,
-    name: &'static str,
+    code: String,
+    name: String,
     prev: Option<Prevision>,
     lat: f64,
     lon: f64,
-    x: f32,
-    y: f32,
-    left_to: i32,
-    right_to: i32,
-    down_to: i32,
-    up_to: i32,
+    x: f64,
+    y: f64,
 }
 
-/// Au démarrage, on génére un vecteur des principales villes de France
+/// Au démarrage, on génére un vecteur des villes de la carte
+/// à partir du fichier de configuration (les villes d'outre-mer
+/// sont placées dans leur encart)
 impl FrancePrevision {
-    fn generate() -> Vec<FrancePrevision> {
-        return vec![
-            FrancePrevision {
-                code: 75001,
-                name: "Paris",
-                prev: None,
-                lat: 48.8592,
-                lon: 2.3417,
-                x: 85.0,
-                y: 120.0,
-                left_to: 5,
-                right_to: 6,
-                down_to: 2,
-                up_to: 8,
-            },
-            FrancePrevision {
-                code: 13000,
-                name: "Marseille",
-                prev: None,
-                lat: 43.29667,
-                lon: 5.37639,
-                x: 115.0,
-                y: 30.0,
-                left_to: 3,
-                right_to: 4,
-                down_to: 8,
-                up_to: 2,
-            },
-            FrancePrevision {
-                code: 69000,
-                name: "Lyon",
-                prev: None,
-                lat: 0.0,
-                lon: 0.0,
-                x: 110.0,
-                y: 73.0,
-                left_to: 7,
-                right_to: 7,
-                down_to: 1,
-                up_to: 0,
-            },
-            FrancePrevision {
-                code: 31000,
-                name: "Toulouse",
-                prev: None,
-                lat: 45.75889,
-                lon: 4.84139,
-                x: 70.0,
-                y: 40.0,
-                left_to: 4,
-                right_to: 1,
-                down_to: 3,
-                up_to: 7,
-            },
-            FrancePrevision {
-                code: 06000,
-                name: "Nice",
-                prev: None,
-                lat: 43.70194,
-                lon: 7.26833,
-                x: 142.0,
-                y: 38.0,
-                left_to: 1,
-                right_to: 3,
-                down_to: 1,
-                up_to: 2,
-            },
-            FrancePrevision {
-                code: 44000,
-                name: "Nantes",
-                prev: None,
-                lat: 0.0,
-                lon: 0.0,
-                x: 40.0,
-                y: 98.0,
-                left_to: 6,
-                right_to: 0,
-                down_to: 7,
-                up_to: 0,
-            },
-            FrancePrevision {
-                code: 67000,
-                name: "Strasbourg",
-                prev: None,
-                lat: 48.58361,
-                lon: 7.74806,
-                x: 140.0,
-                y: 123.0,
-                left_to: 0,
-                right_to: 5,
-                down_to: 2,
-                up_to: 8,
-            },
-            FrancePrevision {
-                code: 33000,
-                name: "Bordeaux",
-                prev: None,
-                lat: 44.83778,
-                lon: -0.57944,
-                x: 48.0,
-                y: 58.0,
-                left_to: 2,
-                right_to: 2,
-                down_to: 3,
-                up_to: 5,
-            },
-            FrancePrevision {
-                code: 59000,
-                name: "Lille",
-                prev: None,
-                lat: 50.63194,
-                lon: 3.0575,
-                x: 90.0,
-                y: 155.0,
-                left_to: 5,
-                right_to: 6,
-                down_to: 0,
-                up_to: 1,
-            },
-            /*FrancePrevision {
-                code: 14000,
-                name: "Caen",
-                prev: None,
-                lat: 49.18222,
-                lon: -0.37056,
-                x: 53.0,
-                y: 130.0,
-                left_to: 0,
-                right_to: 0,
-                down_to: 0,
-                up_to: 0
-            }
-            FrancePrevision {
-                code: 34000,
-                name: "Montepellier",
-                prev: None,
-                lat: 47.21722,
-                lon: -1.55389,
-                x: 85.0,
-                y: 25.0,
-                left_to: 0,
-                right_to: 0,
-                down_to: 0,
-                up_to: 0
-            },*/
-        ];
+    fn generate(villes: Vec<VilleCarte>, carte: &Carte) -> Vec<FrancePrevision> {
+        let previsions: Vec<FrancePrevision> = villes
+            .into_iter()
+            .map(|v| {
+                let (x, y) = carte.projeter(v.lat, v.lon);
+                FrancePrevision {
+                    code: v.postcode,
+                    name: v.name,
+                    prev: None,
+                    lat: v.lat,
+                    lon: v.lon,
+                    x,
+                    y,
+                }
+            })
+            .collect();
+
+        previsions
+    }
+
+    /// Etiquette affichée sur la carte : symbole du temps du jour, nom de la
+    /// ville et température maximale (colorée selon sa valeur).
+    /// La ville sélectionnée est mise en valeur.
+    fn etiquette(&self, selectionnee: bool, symboles: Symboles) -> Spans<'static> {
+        let aujourdhui = self
+            .prev
+            .to_owned()
+            .and_then(|p| p.properties)
+            .and_then(|p| p.daily_forecast)
+            .and_then(|d| d.first().cloned());
+
+        // Une ville sans prévision est grisée
+        let style_nom = if selectionnee {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD)
+        } else if self.prev.is_none() {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+
+        let mut spans = vec![];
+        if let Some(jour) = &aujourdhui {
+            let icon = ascii_icons::get_type_icon_from_str(
+                jour.daily_weather_icon
+                    .to_owned()
+                    .unwrap_or_default()
+                    .as_str(),
+            );
+            spans.push(Span::raw(format!(
+                "{} ",
+                ascii_icons::get_glyphe_icon(icon, symboles)
+            )));
+        }
+        spans.push(Span::styled(self.name.clone(), style_nom));
+        if let Some(t_max) = aujourdhui.and_then(|j| j.t_max) {
+            spans.push(Span::styled(
+                format!(" {:.0}°", t_max),
+                Style::default()
+                    .fg(couleur_temperature(t_max))
+                    .add_modifier(Modifier::BOLD),
+            ))
+        }
+        Spans::from(spans)
+    }
+
+    /// Abscisse (canvas) du début de l'étiquette, centrée sur la ville
+    /// sans dépasser le bord gauche de la vue
+    fn debut_etiquette(&self, largeur_caractere: f64, gauche: f64, symboles: Symboles) -> f64 {
+        let largeur = self.etiquette(false, symboles).width() as f64;
+        (self.x - largeur * largeur_caractere / 2.0).max(gauche)
+    }
+}
+
+/// Paliers de couleur (valeur maximale, couleur) utilisés pour les températures,
+/// du bleu (froid) au rouge (chaud)
+const PALIERS_TEMPERATURE: [(f64, Color); 6] = [
+    (0.0, Color::LightBlue),
+    (10.0, Color::Cyan),
+    (20.0, Color::Green),
+    (25.0, Color::Yellow),
+    (30.0, Color::LightRed),
+    (f64::INFINITY, Color::Red),
+];
+
+/// Paliers de couleur des précipitations sur 24h (en mm)
+const PALIERS_PRECIPITATIONS: [(f64, Color); 6] = [
+    (0.1, Color::Gray),
+    (1.0, Color::LightCyan),
+    (5.0, Color::Cyan),
+    (10.0, Color::LightBlue),
+    (20.0, Color::Blue),
+    (f64::INFINITY, Color::Magenta),
+];
+
+/// Paliers de couleur de l'humidité relative (en %)
+const PALIERS_HUMIDITE: [(f64, Color); 5] = [
+    (40.0, Color::Yellow),
+    (60.0, Color::Green),
+    (75.0, Color::Cyan),
+    (90.0, Color::LightBlue),
+    (f64::INFINITY, Color::Blue),
+];
+
+/// Couleur du premier palier dont la valeur maximale dépasse v
+fn couleur_palier(paliers: &[(f64, Color)], v: f64) -> Color {
+    for (seuil, couleur) in paliers {
+        if v < *seuil {
+            return *couleur;
+        }
+    }
+    paliers.last().map(|p| p.1).unwrap_or(Color::White)
+}
+
+/// Couleur associée à une température, du bleu (froid) au rouge (chaud)
+fn couleur_temperature(t: f64) -> Color {
+    couleur_palier(&PALIERS_TEMPERATURE, t)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+/// Variable représentée en couleur sur la carte (touche "v") :
+/// ses valeurs sont interpolées entre les villes chargées
+enum VariableCarte {
+    Aucune,
+    TemperatureMax,
+    TemperatureMin,
+    Precipitations,
+    Humidite,
+}
+
+impl VariableCarte {
+    fn suivante(&self) -> VariableCarte {
+        match self {
+            VariableCarte::Aucune => VariableCarte::TemperatureMax,
+            VariableCarte::TemperatureMax => VariableCarte::TemperatureMin,
+            VariableCarte::TemperatureMin => VariableCarte::Precipitations,
+            VariableCarte::Precipitations => VariableCarte::Humidite,
+            VariableCarte::Humidite => VariableCarte::Aucune,
+        }
+    }
+
+    fn nom(&self) -> &'static str {
+        match self {
+            VariableCarte::Aucune => "",
+            VariableCarte::TemperatureMax => "Température max (°C)",
+            VariableCarte::TemperatureMin => "Température min (°C)",
+            VariableCarte::Precipitations => "Précipitations (mm/24h)",
+            VariableCarte::Humidite => "Humidité (%)",
+        }
+    }
+
+    fn paliers(&self) -> &'static [(f64, Color)] {
+        match self {
+            VariableCarte::Aucune => &[],
+            VariableCarte::TemperatureMax | VariableCarte::TemperatureMin => &PALIERS_TEMPERATURE,
+            VariableCarte::Precipitations => &PALIERS_PRECIPITATIONS,
+            VariableCarte::Humidite => &PALIERS_HUMIDITE,
+        }
+    }
+
+    /// Valeur de la variable pour une journée de prévisions
+    /// (l'humidité est la moyenne du minimum et du maximum)
+    fn valeur(&self, jour: &DailyForecast) -> Option<f64> {
+        match self {
+            VariableCarte::Aucune => None,
+            VariableCarte::TemperatureMax => jour.t_max,
+            VariableCarte::TemperatureMin => jour.t_min,
+            VariableCarte::Precipitations => jour.total_precipitation_24h,
+            VariableCarte::Humidite => {
+                match (jour.relative_humidity_min, jour.relative_humidity_max) {
+                    (Some(min), Some(max)) => Some((min + max) as f64 / 2.0),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Légende : un carré coloré et l'intervalle de valeurs de chaque palier
+    fn legende(&self) -> Spans<'static> {
+        let paliers = self.paliers();
+        let mut spans = vec![Span::styled(
+            format!("{} :", self.nom()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        for (i, (seuil, couleur)) in paliers.iter().enumerate() {
+            let intervalle = if i == 0 {
+                format!("<{}", seuil)
+            } else if i == paliers.len() - 1 {
+                format!("≥{}", paliers[i - 1].0)
+            } else {
+                format!("{}–{}", paliers[i - 1].0, seuil)
+            };
+            spans.push(Span::styled(" ■ ", Style::default().fg(*couleur)));
+            spans.push(Span::raw(intervalle));
+        }
+        Spans::from(spans)
+    }
+}
+
+/// Grille colorée de la carte : chaque case du terminal située en France
+/// métropolitaine reçoit la valeur interpolée (IDW) à partir des villes
+/// chargées, sans tenir compte des villes des encarts d'outre-mer.
+/// On renvoie, pour chaque couleur, les points braille qui remplissent ses cases.
+fn grille_choroplethe(
+    villes: &[FrancePrevision],
+    carte: &Carte,
+    variable: VariableCarte,
+    zone: Rect,
+    vue: &Vue,
+) -> Vec<(Color, Vec<(f64, f64)>)> {
+    let points: Vec<(f64, f64, f64)> = villes
+        .iter()
+        .filter(|v| !carte.outremer(v.x, v.y))
+        .filter_map(|v| {
+            let jour = v
+                .prev
+                .to_owned()
+                .and_then(|p| p.properties)
+                .and_then(|p| p.daily_forecast)
+                .and_then(|d| d.first().cloned())?;
+            variable.valeur(&jour).map(|valeur| (v.x, v.y, valeur))
+        })
+        .collect();
+    if points.is_empty() || zone.width < 2 || zone.height < 2 {
+        return vec![];
+    }
+
+    // Résolution braille : 2 points par colonne et 4 par ligne
+    let [gauche, droite] = vue.x_bounds();
+    let [bas, haut] = vue.y_bounds();
+    let pas_x = (droite - gauche) / (zone.width * 2 - 1) as f64;
+    let pas_y = (haut - bas) / (zone.height * 4 - 1) as f64;
+
+    let mut grille: Vec<(Color, Vec<(f64, f64)>)> = vec![];
+    for ligne in 0..zone.height {
+        for colonne in 0..zone.width {
+            let x = gauche + (colonne as f64 * 2.0 + 0.5) * pas_x;
+            let y = haut - (ligne as f64 * 4.0 + 1.5) * pas_y;
+            if !carte.dans_contours(x, y) {
+                continue;
+            }
+            let couleur = match interpolation::interpoler_idw(&points, x, y) {
+                Some(valeur) => couleur_palier(variable.paliers(), valeur),
+                None => continue,
+            };
+            let index = match grille.iter().position(|(c, _)| *c == couleur) {
+                Some(i) => i,
+                None => {
+                    grille.push((couleur, vec![]));
+                    grille.len() - 1
+                }
+            };
+            // Points placés au milieu de chaque point braille de la case
+            for i in 0..2 {
+                for j in 0..4 {
+                    let px = gauche + ((colonne * 2 + i) as f64 + 0.5) * pas_x;
+                    let py = haut - ((ligne * 4 + j) as f64 + 0.5) * pas_y;
+                    grille[index].1.push((px.min(droite), py.max(bas)));
+                }
+            }
+        }
+    }
+
+    grille
+}
+
+/// Demi-angle (en degrés) du cône dans lequel on cherche
+/// la ville suivante lors d'un appui sur une flèche
+const ANGLE_NAVIGATION: f64 = 60.0;
+
+/// Recherche de la ville la plus proche dans la direction (dx, dy), parmi
+/// celles situées dans un cône autour de cette direction. Les positions
+/// sont celles des villes projetées sur la carte. La distance est divisée
+/// par le cosinus de l'angle pour favoriser les villes bien alignées.
+fn ville_dans_direction(
+    positions: &[(f64, f64)],
+    depuis: usize,
+    dx: f64,
+    dy: f64,
+) -> Option<usize> {
+    let (x0, y0) = positions[depuis];
+    let cos_max = ANGLE_NAVIGATION.to_radians().cos();
+    let mut meilleure: Option<(usize, f64)> = None;
+
+    for (i, (x, y)) in positions.iter().enumerate() {
+        let distance = ((x - x0).powi(2) + (y - y0).powi(2)).sqrt();
+        if i == depuis || distance == 0.0 {
+            continue;
+        }
+        // Cosinus de l'angle entre la direction demandée et la ville
+        let cos = ((x - x0) * dx + (y - y0) * dy) / distance;
+        if cos < cos_max {
+            continue;
+        }
+        let score = distance / cos;
+        match meilleure {
+            Some((_, s)) if s <= score => {}
+            _ => meilleure = Some((i, score)),
+        }
     }
+
+    meilleure.map(|(i, _)| i)
 }
 
+/// Déplacement du curseur libre de la carte à chaque appui (unités du canvas)
+const PAS_CURSEUR: f64 = 2.0;
+
+/// Facteur appliqué au zoom de la carte à chaque appui sur "+" ou "-"
+const ZOOM_PAS: f64 = 1.5;
+
+/// À partir de ce zoom, les préfectures sont ajoutées sur la carte
+const ZOOM_PREFECTURES: f64 = 2.0;
+
+/// Délai maximum entre deux clics pour un double-clic
+const DELAI_DOUBLE_CLIC: Duration = Duration::from_millis(400);
+
+/// Nombre maximum de recherches gardées dans l'historique
+const HISTORIQUE_TAILLE: usize = 30;
+
 #[derive(Clone)]
 pub enum MeteoTabs {
-    TabCarte,
-    TabPrevision,
+    Carte,
+    Prevision,
+    Horaire,
+}
+
+/// Noms des onglets, dans l'ordre de la barre de navigation
+const NOMS_ONGLETS: [&str; 3] = ["Carte", "Prévisions", "Heure par heure"];
+
+impl MeteoTabs {
+    fn index(&self) -> usize {
+        match self {
+            MeteoTabs::Carte => 0,
+            MeteoTabs::Prevision => 1,
+            MeteoTabs::Horaire => 2,
+        }
+    }
+
+    fn from_index(index: usize) -> Option<MeteoTabs> {
+        match index {
+            0 => Some(MeteoTabs::Carte),
+            1 => Some(MeteoTabs::Prevision),
+            2 => Some(MeteoTabs::Horaire),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+/// Zones de l'écran mémorisées lors du dernier dessin de l'interface,
+/// pour retrouver l'élément sur lequel l'utilisateur a cliqué
+struct ZonesEcran {
+    onglets: Rect,
+    carte: Rect,
+    cartes_jours: Vec<Rect>,
+    tableau_horaire: Rect,
+    /// Colonnes visibles du tableau des risques, avec l'index de leur créneau
+    creneaux_risques: Vec<(usize, Rect)>,
+}
+
+/// Le point (colonne, ligne) du terminal est-il dans la zone ?
+fn zone_contient(zone: Rect, colonne: u16, ligne: u16) -> bool {
+    colonne >= zone.x
+        && colonne < zone.x + zone.width
+        && ligne >= zone.y
+        && ligne < zone.y + zone.height
+}
+
+/// Case du terminal où le canvas de la carte affiche le point (x, y)
+/// (même calcul que celui utilisé par tui pour placer les textes)
+fn position_ecran(zone: Rect, vue: &Vue, x: f64, y: f64) -> (u16, u16) {
+    let [gauche, _] = vue.x_bounds();
+    let [_, haut] = vue.y_bounds();
+    let etendue = 2.0 * vue.demi_etendue();
+    let colonne = (x - gauche) * (zone.width.max(1) - 1) as f64 / etendue;
+    let ligne = (haut - y) * (zone.height.max(1) - 1) as f64 / etendue;
+    (zone.x + colonne as u16, zone.y + ligne as u16)
+}
+
+/// Point du canvas de la carte situé sous une case du terminal
+fn position_canvas(zone: Rect, vue: &Vue, colonne: u16, ligne: u16) -> (f64, f64) {
+    let [gauche, _] = vue.x_bounds();
+    let [_, haut] = vue.y_bounds();
+    let etendue = 2.0 * vue.demi_etendue();
+    let x = (colonne - zone.x) as f64 * etendue / (zone.width.max(2) - 1) as f64;
+    let y = haut - (ligne - zone.y) as f64 * etendue / (zone.height.max(2) - 1) as f64;
+    (gauche + x, y)
+}
+
+/// Largeur d'un caractère du terminal en unités du canvas,
+/// pour centrer les noms des villes
+fn largeur_caractere(zone: Rect, vue: &Vue) -> f64 {
+    2.0 * vue.demi_etendue() / zone.width.max(1) as f64
 }
 
 #[derive(Clone)]
 enum Mode {
-    InputMode,
-    ReadMode,
+    Input,
+    Read,
+    Label,
 }
 
 #[derive(Clone)]
@@ -223,14 +480,50 @@ pub struct MeteoApp {
     search: String,
     search_error: bool,
     search_error_message: String,
+    search_brouillon: String,
+    historique: Vec<Recherche>,
+    historique_index: Option<usize>,
     previsions_france: Vec<FrancePrevision>,
     previsions_france_selected: i32,
+    curseur: Option<(f64, f64)>,
+    prevision_curseur: Option<Prevision>,
+    carte: Carte,
+    vue: Vue,
+    prefectures: Vec<VilleCarte>,
+    variable_carte: VariableCarte,
+    zones: ZonesEcran,
+    dernier_clic: Option<(Instant, usize)>,
+    jour_deplie: Option<usize>,
+    jour_selectionne: usize,
+    defilement_horaire: u16,
+    etat_horaire: TableState,
+    creneau_survole: Option<usize>,
     prevision: Option<Prevision>,
     default_place: Place,
     actual_place: Place,
+    favoris: Vec<Favori>,
+    favori_label: String,
+    previsions_cache: HashMap<String, Prevision>,
+    symboles: Symboles,
+    icones: PackIcons,
     exit: bool,
 }
 
+/// Deux lieux sont considérés identiques s'ils partagent
+/// le même code INSEE et le même code postal
+fn meme_place(a: &Place, b: &Place) -> bool {
+    a.insee == b.insee && a.postCode == b.postCode
+}
+
+/// Clé utilisée pour garder en cache les prévisions d'un lieu
+fn cle_place(place: &Place) -> String {
+    format!(
+        "{}-{}",
+        place.insee.to_owned().unwrap_or_default(),
+        place.postCode.to_owned().unwrap_or_default()
+    )
+}
+
 impl MeteoApp {
     fn new(meteo_client: &MeteoFranceAPI, postcode: Option<i32>) -> Result<MeteoApp, MeteoErreurs> {
         let mut p = meteo_client.get_default_place()?;
@@ -244,129 +537,390 @@ impl MeteoApp {
             None => p,
         };
 
-        let mut previsions_france = FrancePrevision::generate();
+        let villes_lues = meteo_client.get_villes_carte();
+        if villes_lues.is_err() {
+            let _ =
+                storage::journaliser_erreur("carte.toml illisible, villes par défaut utilisées");
+        }
+        let mut villes = villes_lues.unwrap_or_default().villes;
+        if villes.is_empty() {
+            villes = VillesCarte::default().villes;
+        }
+
+        let affichage = meteo_client.get_affichage().unwrap_or_default();
+        let carte = Carte::new(&meteo_client.get_contours()?, &affichage.contour);
+        let mut previsions_france = FrancePrevision::generate(villes, &carte);
         for c in &mut previsions_france {
             let place = Place {
-                name: Some(c.name.to_string()),
+                name: Some(c.name.clone()),
                 insee: Some(String::new()),
                 lat: Some(c.lat),
                 lon: Some(c.lon),
                 country: Some(String::new()),
                 admin: Some(String::new()),
                 admin2: Some(String::new()),
-                postCode: Some(c.code.to_string()),
+                postCode: Some(c.code.clone()),
             };
-            let prev_option = meteo_client.get_prevision(place)?;
-            c.prev = Some(prev_option);
+            // Une ville dont la prévision échoue reste sur la carte, sans météo
+            match meteo_client.get_prevision(place) {
+                Ok(prev) => c.prev = Some(prev),
+                Err(_) => {
+                    let _ = storage::journaliser_erreur(&format!(
+                        "prévisions indisponibles pour {} ({})",
+                        c.name, c.code
+                    ));
+                }
+            }
         }
 
-        let prevision = match meteo_client.get_prevision(p.clone()) {
-            Ok(prev) => Some(prev),
-            Err(_) => None,
-        };
+        let prevision = meteo_client.get_prevision(p.clone()).ok();
+
+        let mut previsions_cache = HashMap::new();
+        if let Some(prev) = &prevision {
+            previsions_cache.insert(cle_place(&p), prev.clone());
+        }
+
+        // Un fichier des favoris illisible ne doit pas empêcher le démarrage :
+        // il est signalé dans le journal et laissé tel quel
+        let favoris_lus = meteo_client.get_favoris();
+        let favoris_illisibles = favoris_lus.is_err();
+        if favoris_illisibles {
+            let _ = storage::journaliser_erreur("favoris.toml illisible, favoris ignorés");
+        }
+
+        // Au premier lancement, la ville par défaut devient le premier favori
+        let mut favoris = favoris_lus.unwrap_or_default().favoris;
+        if favoris.is_empty() && !favoris_illisibles {
+            favoris.push(Favori {
+                label: default_place
+                    .name
+                    .to_owned()
+                    .unwrap_or(String::from("Maison")),
+                defaut: true,
+                place: default_place.clone(),
+            });
+            meteo_client.enregistrer_favoris(Favoris {
+                favoris: favoris.clone(),
+            });
+        }
 
-        return Ok(MeteoApp {
-            tab: MeteoTabs::TabCarte,
-            mode: Mode::ReadMode,
+        Ok(MeteoApp {
+            tab: MeteoTabs::Carte,
+            mode: Mode::Read,
             search: String::new(),
             search_error: false,
             search_error_message: String::new(),
-            previsions_france: previsions_france,
+            search_brouillon: String::new(),
+            historique: meteo_client.get_historique().unwrap_or_default().recherches,
+            historique_index: None,
+            previsions_france,
             previsions_france_selected: 0,
-            prevision: prevision,
+            curseur: None,
+            prevision_curseur: None,
+            carte,
+            vue: Vue::entiere(),
+            prefectures: VillesCarte::prefectures().villes,
+            variable_carte: VariableCarte::Aucune,
+            zones: ZonesEcran::default(),
+            dernier_clic: None,
+            jour_deplie: None,
+            jour_selectionne: 0,
+            defilement_horaire: 0,
+            etat_horaire: TableState::default(),
+            creneau_survole: None,
+            prevision,
             default_place: default_place.clone(),
             actual_place: p,
+            favoris,
+            favori_label: String::new(),
+            previsions_cache,
+            symboles: affichage.symboles,
+            icones: meteo_client
+                .get_pack_icons(&affichage.pack_icons)
+                .unwrap_or_default(),
             exit: false,
-        });
+        })
     }
 
     fn tab_key(&mut self) {
         self.tab = match self.tab {
-            MeteoTabs::TabCarte => MeteoTabs::TabPrevision,
-            MeteoTabs::TabPrevision => MeteoTabs::TabCarte,
+            MeteoTabs::Carte => MeteoTabs::Prevision,
+            MeteoTabs::Prevision => MeteoTabs::Horaire,
+            MeteoTabs::Horaire => MeteoTabs::Carte,
         };
     }
 
     fn search(&mut self) {
+        if let Mode::Read = self.mode {
+            self.mode = Mode::Input;
+            self.search_error = false;
+            self.historique_index = None;
+        }
+
+        if let MeteoTabs::Carte = self.tab {
+            self.tab = MeteoTabs::Carte;
+        }
+    }
+
+    fn esc(&mut self) {
+        if self.jour_deplie.is_some() {
+            self.jour_deplie = None;
+            return;
+        }
         match self.mode {
-            Mode::ReadMode => {
-                self.mode = Mode::InputMode;
-                self.search_error = false;
+            Mode::Input => self.mode = Mode::Read,
+            Mode::Label => {
+                self.mode = Mode::Read;
+                self.favori_label.clear();
             }
-            _ => {}
+            _ => self.exit = true,
         }
+    }
 
-        match self.tab {
-            MeteoTabs::TabCarte => {
-                self.tab = MeteoTabs::TabCarte;
+    /// Touches du mode recherche, les mêmes dans tous les onglets : saisie
+    /// du code postal, validation, historique et sortie
+    fn touche_recherche(&mut self, code: KeyCode, client: &MeteoFranceAPI) {
+        match code {
+            KeyCode::Esc => {
+                self.esc();
+            }
+            KeyCode::Enter => {
+                self.enter(client);
+            }
+            KeyCode::Up => {
+                self.historique_precedent();
+            }
+            KeyCode::Down => {
+                self.historique_suivant();
+            }
+            KeyCode::Backspace => {
+                self.search.pop();
+                self.historique_index = None;
+            }
+            KeyCode::Char(c) => {
+                self.search.push(c);
+                self.historique_index = None;
             }
             _ => {}
         }
     }
 
-    fn esc(&mut self) {
-        match self.mode {
-            Mode::InputMode => self.mode = Mode::ReadMode,
-            _ => self.exit = true,
+    /// Touche "↑" en mode recherche : on remonte dans l'historique.
+    /// Le texte en cours de saisie est gardé de côté pour y revenir avec "↓".
+    fn historique_precedent(&mut self) {
+        if self.historique.is_empty() {
+            return;
+        }
+        let index = match self.historique_index {
+            Some(i) => (i + 1).min(self.historique.len() - 1),
+            None => {
+                self.search_brouillon = self.search.clone();
+                0
+            }
+        };
+        self.historique_index = Some(index);
+        self.search = self.historique[index].requete.clone();
+    }
+
+    /// Touche "↓" en mode recherche : on redescend vers les recherches récentes
+    fn historique_suivant(&mut self) {
+        match self.historique_index {
+            Some(0) => {
+                self.historique_index = None;
+                self.search = self.search_brouillon.clone();
+            }
+            Some(i) => {
+                self.historique_index = Some(i - 1);
+                self.search = self.historique[i - 1].requete.clone();
+            }
+            None => {}
+        }
+    }
+
+    /// Une recherche réussie passe en tête de l'historique (sans doublon)
+    fn ajouter_historique(&mut self, client: &MeteoFranceAPI, place: &Place) {
+        let requete = self.search.trim().to_string();
+        self.historique.retain(|r| r.requete != requete);
+        self.historique.insert(
+            0,
+            Recherche {
+                requete,
+                commune: place.name.to_owned().unwrap_or_default(),
+            },
+        );
+        self.historique.truncate(HISTORIQUE_TAILLE);
+        self.historique_index = None;
+        client.enregistrer_historique(Historique {
+            recherches: self.historique.clone(),
+        });
+    }
+
+    /// Position du lieu actuellement affiché dans la liste des favoris
+    fn index_favori_actuel(&self) -> Option<usize> {
+        self.favoris
+            .iter()
+            .position(|f| meme_place(&f.place, &self.actual_place))
+    }
+
+    fn enregistrer_favoris(&mut self, client: &MeteoFranceAPI) {
+        let ok = client.enregistrer_favoris(Favoris {
+            favoris: self.favoris.clone(),
+        });
+        if !ok {
+            self.search_error = true;
+            self.search_error_message = String::from("Erreur : favoris non enregistrés");
         }
     }
 
+    /// Le lieu affiché devient la ville par défaut (et un favori s'il ne l'était pas)
     fn fav(&mut self, client: &MeteoFranceAPI) {
-        let code = self
-            .actual_place
-            .postCode
-            .to_owned()
-            .unwrap()
-            .parse::<i32>();
-        match code {
-            Ok(c) => {
-                client.changer_default_ville(c);
-                self.default_place = self.actual_place.clone();
+        let index = match self.index_favori_actuel() {
+            Some(i) => i,
+            None => {
+                self.favoris.push(Favori {
+                    label: self
+                        .actual_place
+                        .name
+                        .to_owned()
+                        .unwrap_or(String::from("Favori")),
+                    defaut: false,
+                    place: self.actual_place.clone(),
+                });
+                self.favoris.len() - 1
+            }
+        };
+
+        for (i, f) in self.favoris.iter_mut().enumerate() {
+            f.defaut = i == index;
+        }
+        self.default_place = self.actual_place.clone();
+        self.enregistrer_favoris(client);
+    }
+
+    /// Touche "a" : on passe en saisie du libellé du nouveau favori
+    fn ajouter_favori(&mut self) {
+        self.mode = Mode::Label;
+        self.favori_label = match self.index_favori_actuel() {
+            Some(i) => self.favoris[i].label.clone(),
+            None => String::new(),
+        };
+    }
+
+    /// Validation du libellé : ajout du favori, ou renommage s'il existe déjà
+    fn valider_favori(&mut self, client: &MeteoFranceAPI) {
+        self.mode = Mode::Read;
+        let mut label = self.favori_label.trim().to_string();
+        self.favori_label.clear();
+        if label.is_empty() {
+            label = self
+                .actual_place
+                .name
+                .to_owned()
+                .unwrap_or(String::from("Favori"));
+        }
+
+        match self.index_favori_actuel() {
+            Some(i) => self.favoris[i].label = label,
+            None => {
+                let defaut = self.favoris.is_empty();
+                self.favoris.push(Favori {
+                    label,
+                    defaut,
+                    place: self.actual_place.clone(),
+                });
+            }
+        }
+        self.enregistrer_favoris(client);
+    }
+
+    /// Touche "d" : le lieu affiché est retiré des favoris. Si c'était
+    /// la ville par défaut, le premier favori restant prend le relais.
+    fn supprimer_favori(&mut self, client: &MeteoFranceAPI) {
+        if let Some(i) = self.index_favori_actuel() {
+            let retire = self.favoris.remove(i);
+            if retire.defaut && !self.favoris.is_empty() {
+                self.favoris[0].defaut = true;
+                self.default_place = self.favoris[0].place.clone();
+            }
+            self.enregistrer_favoris(client);
+        }
+    }
+
+    /// Touches "1" à "9" : affichage des prévisions d'un favori. Les prévisions
+    /// déjà chargées sont gardées en cache pour passer d'un favori à l'autre
+    /// sans attendre.
+    fn ouvrir_favori(&mut self, client: &MeteoFranceAPI, index: usize) {
+        if index >= self.favoris.len() {
+            return;
+        }
+        let place = self.favoris[index].place.clone();
+        let cle = cle_place(&place);
+
+        let prevision = match self.previsions_cache.get(&cle) {
+            Some(prev) => Some(prev.clone()),
+            None => client.get_prevision(place.clone()).ok(),
+        };
+
+        match prevision {
+            Some(prev) => {
+                self.previsions_cache.insert(cle, prev.clone());
+                self.prevision = Some(prev);
+                self.etat_horaire.select(None);
+                self.jour_selectionne = 0;
+                self.jour_deplie = None;
+                self.actual_place = place;
+                self.tab = MeteoTabs::Prevision;
+                self.search_error = false;
+            }
+            None => {
+                self.search_error = true;
+                self.search_error_message = String::from("Erreur : prévisions indisponibles");
             }
-            Err(_) => {}
         }
     }
 
     fn enter(&mut self, client: &MeteoFranceAPI) {
-        match self.mode {
-            Mode::InputMode => {
-                self.mode = Mode::ReadMode;
-                let code_postal = self.search.parse::<i32>();
-                match code_postal {
-                    Ok(c) => {
-                        let new_prevision = client.get_place(c);
-                        match new_prevision {
-                            Ok(p) => {
-                                match client.get_prevision(p.clone()) {
-                                    Ok(prev) => {
-                                        self.prevision = Some(prev);
-                                        self.actual_place = p;
-                                        self.tab = MeteoTabs::TabPrevision;
-                                        self.search_error = false
-                                    }
-                                    Err(_) => {
-                                        // Si aucune prévision trouvée
-                                        self.search_error = true;
-                                        self.search_error_message = String::from("Erreur")
-                                    }
+        if let Mode::Input = self.mode {
+            self.mode = Mode::Read;
+            let code_postal = self.search.parse::<i32>();
+            match code_postal {
+                Ok(c) => {
+                    let new_prevision = client.get_place(c);
+                    match new_prevision {
+                        Ok(p) => {
+                            match client.get_prevision(p.clone()) {
+                                Ok(prev) => {
+                                    self.ajouter_historique(client, &p);
+                                    self.previsions_cache.insert(cle_place(&p), prev.clone());
+                                    self.prevision = Some(prev);
+                                    self.etat_horaire.select(None);
+                                    self.jour_selectionne = 0;
+                                    self.jour_deplie = None;
+                                    self.actual_place = p;
+                                    self.tab = MeteoTabs::Prevision;
+                                    self.search_error = false
+                                }
+                                Err(_) => {
+                                    // Si aucune prévision trouvée
+                                    self.search_error = true;
+                                    self.search_error_message = String::from("Erreur")
                                 }
-                            }
-                            Err(_) => {
-                                // Si aucun endroit trouvé dans l'API météo France
-                                self.search_error = true;
-                                self.search_error_message =
-                                    String::from("Erreur : code postal incorrect");
                             }
                         }
-                    }
-                    Err(_) => {
-                        // Si la recherche n'est pas un entier
-                        self.search_error = true;
-                        self.search_error_message = String::from("Erreur : entrez un code postal")
+                        Err(_) => {
+                            // Si aucun endroit trouvé dans l'API météo France
+                            self.search_error = true;
+                            self.search_error_message =
+                                String::from("Erreur : code postal incorrect");
+                        }
                     }
                 }
+                Err(_) => {
+                    // Si la recherche n'est pas un entier
+                    self.search_error = true;
+                    self.search_error_message = String::from("Erreur : entrez un code postal")
+                }
             }
-            _ => {}
         }
     }
 
@@ -374,14 +928,21 @@ impl MeteoApp {
         let i: usize = self.previsions_france_selected.try_into().unwrap();
         let ville = self.previsions_france[i].clone();
 
-        self.tab = MeteoTabs::TabPrevision;
-        let new_prevision = client.get_place(ville.code);
+        self.tab = MeteoTabs::Prevision;
+        let new_prevision = match ville.code.parse::<i32>() {
+            Ok(code) => client.get_place(code),
+            Err(_) => Err(MeteoErreurs::BadPlaceRequest),
+        };
         match new_prevision {
             Ok(p) => {
                 match client.get_prevision(p.clone()) {
                     Ok(prev) => {
+                        self.previsions_cache.insert(cle_place(&p), prev.clone());
                         self.prevision = Some(prev);
-                        self.search = ville.code.to_string();
+                        self.etat_horaire.select(None);
+                        self.jour_selectionne = 0;
+                        self.jour_deplie = None;
+                        self.search = ville.code.clone();
                         self.actual_place = p;
                         self.search_error = false
                     }
@@ -400,63 +961,377 @@ impl MeteoApp {
         }
     }
 
-    fn left(&mut self) {
-        match self.tab {
-            MeteoTabs::TabCarte => {
+    /// Touche "c" : passage de la navigation entre villes au curseur libre
+    /// (et inversement). Le curseur part de la ville sélectionnée.
+    fn basculer_curseur(&mut self) {
+        self.curseur = match self.curseur {
+            Some(_) => None,
+            None => {
+                // Le curseur part de la ville sélectionnée, ou du centre
+                // de la vue si elle n'est pas affichée
                 let i: usize = self.previsions_france_selected.try_into().unwrap();
-                let ville = self.previsions_france[i].clone();
+                let ville = &self.previsions_france[i];
+                if self.vue.contient(ville.x, ville.y) {
+                    Some((ville.x, ville.y))
+                } else {
+                    Some(self.vue.centre)
+                }
+            }
+        };
+        self.prevision_curseur = None;
+    }
 
-                self.previsions_france_selected = ville.left_to;
+    /// Touche "ENTRER" en mode curseur libre : on récupère les prévisions
+    /// du point de la carte situé sous le curseur
+    fn prevision_point(&mut self, client: &MeteoFranceAPI) {
+        let (x, y) = match self.curseur {
+            Some(c) => c,
+            None => return,
+        };
+        let place = self.carte.place(x, y);
+        match client.get_prevision(place) {
+            Ok(prev) => {
+                self.prevision_curseur = Some(prev);
+                self.search_error = false;
+            }
+            Err(_) => {
+                self.prevision_curseur = None;
+                self.search_error = true;
+                self.search_error_message = String::from("Erreur : prévisions indisponibles");
             }
-            _ => {}
         }
     }
 
-    fn right(&mut self) {
-        match self.tab {
-            MeteoTabs::TabCarte => {
-                let i: usize = self.previsions_france_selected.try_into().unwrap();
-                let ville = self.previsions_france[i].clone();
+    /// Déplacement de la sélection sur la carte dans la direction (dx, dy)
+    fn deplacer_selection(&mut self, dx: f64, dy: f64) {
+        if let MeteoTabs::Carte = self.tab {
+            if let Some((x, y)) = self.curseur {
+                // Le pas et les limites du curseur suivent le zoom
+                let [gauche, droite] = self.vue.x_bounds();
+                let [bas, haut] = self.vue.y_bounds();
+                let pas = PAS_CURSEUR / self.vue.zoom;
+                let x = (x + dx * pas).clamp(gauche, droite);
+                let y = (y + dy * pas).clamp(bas, haut);
+                self.curseur = Some((x, y));
+                return;
+            }
 
-                self.previsions_france_selected = ville.right_to;
+            let i: usize = self.previsions_france_selected.try_into().unwrap();
+            let positions: Vec<(f64, f64)> =
+                self.previsions_france.iter().map(|p| (p.x, p.y)).collect();
+
+            if let Some(j) = ville_dans_direction(&positions, i, dx, dy) {
+                self.previsions_france_selected = j as i32;
+                // La vue suit la sélection si elle sort de l'écran
+                let (x, y) = positions[j];
+                if !self.vue.contient(x, y) {
+                    self.vue.centrer(x, y);
+                }
             }
-            _ => {}
         }
     }
 
-    fn up(&mut self) {
-        match self.tab {
-            MeteoTabs::TabCarte => {
+    /// Touches "+" et "-" : zoom sur le curseur libre ou sur la ville sélectionnée
+    fn zoomer(&mut self, facteur: f64) {
+        let vers = match self.curseur {
+            Some(c) => c,
+            None => {
                 let i: usize = self.previsions_france_selected.try_into().unwrap();
-                let ville = self.previsions_france[i].clone();
+                let ville = &self.previsions_france[i];
+                (ville.x, ville.y)
+            }
+        };
+        self.vue.zoomer(facteur, vers);
+    }
 
-                self.previsions_france_selected = ville.up_to;
+    /// Maj + flèches : déplacement de la vue de la carte
+    fn deplacer_vue(&mut self, dx: f64, dy: f64) {
+        self.vue.deplacer(dx, dy);
+        if let Some((x, y)) = self.curseur {
+            if !self.vue.contient(x, y) {
+                self.curseur = Some(self.vue.centre);
+                self.prevision_curseur = None;
             }
-            _ => {}
         }
     }
 
+    fn left(&mut self) {
+        self.deplacer_selection(-1.0, 0.0);
+    }
+
+    fn right(&mut self) {
+        self.deplacer_selection(1.0, 0.0);
+    }
+
+    fn up(&mut self) {
+        self.deplacer_selection(0.0, 1.0);
+    }
+
     fn down(&mut self) {
-        match self.tab {
-            MeteoTabs::TabCarte => {
-                let i: usize = self.previsions_france_selected.try_into().unwrap();
-                let ville = self.previsions_france[i].clone();
+        self.deplacer_selection(0.0, -1.0);
+    }
 
-                self.previsions_france_selected = ville.down_to;
+    /// Gestion de la souris : clic sur les onglets, les villes de la carte,
+    /// les jours des prévisions et les créneaux des risques, molette pour
+    /// les vues heure par heure, survol des créneaux des risques
+    fn souris(&mut self, evenement: MouseEvent, client: &MeteoFranceAPI) {
+        match self.mode {
+            Mode::Read => {}
+            _ => return,
+        }
+        match evenement.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.clic(evenement.column, evenement.row, client)
+            }
+            MouseEventKind::ScrollDown => self.defiler(1),
+            MouseEventKind::ScrollUp => self.defiler(-1),
+            MouseEventKind::Moved => {
+                self.creneau_survole = self.creneau_sous_souris(evenement.column, evenement.row)
             }
             _ => {}
         }
     }
-}
 
-pub fn init(
-    opening_tab: MeteoTabs,
-    opening_postcode: Option<i32>,
+    fn clic(&mut self, colonne: u16, ligne: u16, client: &MeteoFranceAPI) {
+        if zone_contient(self.zones.onglets, colonne, ligne) {
+            if let Some(tab) = onglet_sous_souris(self.zones.onglets, colonne) {
+                self.tab = tab
+            }
+            return;
+        }
+
+        match self.tab {
+            MeteoTabs::Carte => {
+                if !zone_contient(self.zones.carte, colonne, ligne) {
+                    return;
+                }
+                match self.ville_sous_souris(colonne, ligne) {
+                    Some(i) => {
+                        let double_clic = match self.dernier_clic {
+                            Some((instant, j)) => j == i && instant.elapsed() < DELAI_DOUBLE_CLIC,
+                            None => false,
+                        };
+                        self.previsions_france_selected = i as i32;
+                        self.curseur = None;
+                        self.prevision_curseur = None;
+                        if double_clic {
+                            self.dernier_clic = None;
+                            self.select(client);
+                        } else {
+                            self.dernier_clic = Some((Instant::now(), i));
+                        }
+                    }
+                    None => {
+                        // En mode curseur libre, un clic déplace le curseur
+                        if self.curseur.is_some() {
+                            self.curseur =
+                                Some(position_canvas(self.zones.carte, &self.vue, colonne, ligne));
+                            self.prevision_curseur = None;
+                        }
+                    }
+                }
+            }
+            MeteoTabs::Prevision => {
+                let carte = self
+                    .zones
+                    .cartes_jours
+                    .iter()
+                    .position(|zone| zone_contient(*zone, colonne, ligne));
+                // Les cartes commencent au lendemain (daily_forecast[1])
+                if let Some(i) = carte {
+                    self.jour_deplie = if self.jour_deplie == Some(i + 1) {
+                        None
+                    } else {
+                        Some(i + 1)
+                    };
+                    self.jour_selectionne = i;
+                    self.defilement_horaire = 0;
+                }
+            }
+            MeteoTabs::Horaire => {
+                // Sélection de la première heure du créneau dans le tableau
+                if let Some(i) = self.creneau_sous_souris(colonne, ligne) {
+                    let creneaux = match &self.prevision {
+                        Some(prev) => creneaux_risques(prev),
+                        None => vec![],
+                    };
+                    let debut = creneaux[i].0;
+                    if let Some(ligne) = self.heures().iter().position(|(h, _)| *h >= debut) {
+                        self.etat_horaire.select(Some(ligne));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Créneau du tableau des risques sous la souris (onglet Heure par heure)
+    fn creneau_sous_souris(&self, colonne: u16, ligne: u16) -> Option<usize> {
+        match self.tab {
+            MeteoTabs::Horaire => {}
+            _ => return None,
+        }
+        self.zones
+            .creneaux_risques
+            .iter()
+            .find(|(_, zone)| zone_contient(*zone, colonne, ligne))
+            .map(|(i, _)| *i)
+    }
+
+    /// Ville de la carte dont le nom est affiché sous la souris
+    fn ville_sous_souris(&self, colonne: u16, ligne: u16) -> Option<usize> {
+        let zone = self.zones.carte;
+        let largeur = largeur_caractere(zone, &self.vue);
+        let gauche = self.vue.x_bounds()[0];
+        for (i, p) in self.previsions_france.iter().enumerate() {
+            if !self.vue.contient(p.x, p.y) {
+                continue;
+            }
+            let debut = p.debut_etiquette(largeur, gauche, self.symboles);
+            let (debut, y) = position_ecran(zone, &self.vue, debut, p.y);
+            let largeur_etiquette = p.etiquette(false, self.symboles).width() as u16;
+            if ligne == y && colonne >= debut && colonne < debut + largeur_etiquette {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    /// Nombre de cartes des jours affichées (à partir du lendemain)
+    fn nombre_cartes_jours(&self) -> usize {
+        let jours = self
+            .prevision
+            .as_ref()
+            .and_then(|p| p.properties.as_ref())
+            .and_then(|p| p.daily_forecast.as_ref())
+            .map(|d| d.len())
+            .unwrap_or(0);
+        jours.saturating_sub(1).min(8)
+    }
+
+    /// Touches "←" et "→" de l'onglet Prévisions : choix de la carte du
+    /// jour. Si le détail d'un jour est ouvert, il suit la carte choisie.
+    fn choisir_jour(&mut self, pas: i32) {
+        let nombre = self.nombre_cartes_jours();
+        if nombre == 0 {
+            return;
+        }
+        self.jour_selectionne =
+            (self.jour_selectionne as i32 + pas).clamp(0, nombre as i32 - 1) as usize;
+        if self.jour_deplie.is_some() {
+            self.jour_deplie = Some(self.jour_selectionne + 1);
+            self.defilement_horaire = 0;
+        }
+    }
+
+    /// Touche "ENTRER" de l'onglet Prévisions : ouverture (ou fermeture) du
+    /// détail du jour choisi
+    fn ouvrir_jour(&mut self) {
+        if self.nombre_cartes_jours() == 0 {
+            return;
+        }
+        // Les cartes commencent au lendemain (daily_forecast[1])
+        self.jour_deplie = match self.jour_deplie {
+            Some(_) => None,
+            None => Some(self.jour_selectionne + 1),
+        };
+        self.defilement_horaire = 0;
+    }
+
+    /// Molette de la souris : défilement de la vue heure par heure
+    fn defiler(&mut self, pas: i32) {
+        match self.tab {
+            MeteoTabs::Horaire => self.deplacer_horaire(pas as i64),
+            _ => {
+                if self.jour_deplie.is_some() {
+                    self.defilement_horaire = (self.defilement_horaire as i32 + pas).max(0) as u16;
+                }
+            }
+        }
+    }
+
+    /// Heures de la prévision affichée, dans le fuseau du lieu
+    fn heures(&self) -> Vec<(DateTime<FixedOffset>, Forecast)> {
+        match &self.prevision {
+            Some(prev) => previsions_horaires(prev),
+            None => vec![],
+        }
+    }
+
+    /// Déplacement de la ligne sélectionnée dans le tableau heure par heure
+    fn deplacer_horaire(&mut self, pas: i64) {
+        let nombre = self.heures().len() as i64;
+        if nombre == 0 {
+            return;
+        }
+        let actuelle = self.etat_horaire.selected().unwrap_or(0) as i64;
+        let ligne = (actuelle + pas).clamp(0, nombre - 1);
+        self.etat_horaire.select(Some(ligne as usize));
+    }
+
+    /// Touches "PAGE ↑" et "PAGE ↓" : on avance d'un écran du tableau
+    /// (sa hauteur moins le cadre et la ligne des titres)
+    fn page_horaire(&mut self, sens: i64) {
+        let page = self.zones.tableau_horaire.height.saturating_sub(3).max(1) as i64;
+        self.deplacer_horaire(sens * page);
+    }
+
+    /// Touches "←" et "→" : saut à la première heure du jour précédent
+    /// (ou du jour affiché si l'on n'est pas sur sa première heure) ou suivant
+    fn jour_horaire(&mut self, sens: i64) {
+        let heures = self.heures();
+        if heures.is_empty() {
+            return;
+        }
+        let actuelle = self
+            .etat_horaire
+            .selected()
+            .unwrap_or(0)
+            .min(heures.len() - 1);
+        let date = heures[actuelle].0.date_naive();
+        let debut_du_jour = heures
+            .iter()
+            .position(|(h, _)| h.date_naive() == date)
+            .unwrap_or(0);
+        let ligne = if sens > 0 {
+            heures
+                .iter()
+                .position(|(h, _)| h.date_naive() > date)
+                .unwrap_or(actuelle)
+        } else if actuelle > debut_du_jour {
+            debut_du_jour
+        } else {
+            let veille = heures[..debut_du_jour].last().map(|(h, _)| h.date_naive());
+            heures
+                .iter()
+                .position(|(h, _)| Some(h.date_naive()) == veille)
+                .unwrap_or(actuelle)
+        };
+        self.etat_horaire.select(Some(ligne));
+    }
+}
+
+/// Onglet dont le titre est sous la souris. Les titres sont séparés
+/// d'un espace, d'un trait vertical puis d'un espace (cf. tui::widgets::Tabs).
+fn onglet_sous_souris(zone: Rect, colonne: u16) -> Option<MeteoTabs> {
+    let mut x = zone.x + 2;
+    for (i, nom) in NOMS_ONGLETS.iter().enumerate() {
+        let largeur = nom.width() as u16;
+        if colonne >= x && colonne < x + largeur {
+            return MeteoTabs::from_index(i);
+        }
+        x += largeur + 3;
+    }
+    None
+}
+
+pub fn init(
+    opening_tab: MeteoTabs,
+    opening_postcode: Option<i32>,
     meteo_client: &MeteoFranceAPI,
 ) -> Result<(), io::Error> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -493,11 +1368,15 @@ pub fn ui(
         // "Dessin" de notre UI
         terminal.draw(|f| draw(f, meteo_app))?;
 
-        // Gestion des touches du clavier
-        if let Event::Key(key) = event::read()? {
+        // Gestion des touches du clavier et de la souris
+        let evenement = event::read()?;
+        if let Event::Mouse(souris) = evenement {
+            meteo_app.souris(souris, meteo_client);
+        }
+        if let Event::Key(key) = evenement {
             match meteo_app.tab {
-                MeteoTabs::TabPrevision => match meteo_app.mode {
-                    Mode::ReadMode => match key.code {
+                MeteoTabs::Prevision => match meteo_app.mode {
+                    Mode::Read => match key.code {
                         KeyCode::Char('q') => {
                             return Ok(());
                         }
@@ -507,47 +1386,80 @@ pub fn ui(
                         KeyCode::Char('f') => {
                             meteo_app.fav(meteo_client);
                         }
+                        KeyCode::Char('a') => {
+                            meteo_app.ajouter_favori();
+                        }
+                        KeyCode::Char('d') => {
+                            meteo_app.supprimer_favori(meteo_client);
+                        }
+                        KeyCode::Char(c @ '1'..='9') => {
+                            let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+                            meteo_app.ouvrir_favori(meteo_client, index);
+                        }
                         KeyCode::Esc => {
                             meteo_app.esc();
                         }
                         KeyCode::Tab => {
                             meteo_app.tab_key();
                         }
-                        KeyCode::Enter => meteo_app.enter(meteo_client),
+                        KeyCode::Left => {
+                            meteo_app.choisir_jour(-1);
+                        }
+                        KeyCode::Right => {
+                            meteo_app.choisir_jour(1);
+                        }
+                        KeyCode::Up => {
+                            meteo_app.defiler(-1);
+                        }
+                        KeyCode::Down => {
+                            meteo_app.defiler(1);
+                        }
+                        KeyCode::Enter => meteo_app.ouvrir_jour(),
                         _ => {}
                     },
-                    Mode::InputMode => match key.code {
+                    Mode::Input => meteo_app.touche_recherche(key.code, meteo_client),
+                    Mode::Label => match key.code {
                         KeyCode::Esc => {
                             meteo_app.esc();
                         }
                         KeyCode::Enter => {
-                            meteo_app.enter(meteo_client);
+                            meteo_app.valider_favori(meteo_client);
                         }
                         KeyCode::Backspace => {
-                            meteo_app.search.pop();
+                            meteo_app.favori_label.pop();
                         }
                         KeyCode::Char(c) => {
-                            meteo_app.search.push(c);
+                            meteo_app.favori_label.push(c);
                         }
                         _ => {}
                     },
                 },
-                MeteoTabs::TabCarte => match meteo_app.mode {
-                    Mode::ReadMode => match key.code {
-                        KeyCode::Enter => {
-                            meteo_app.select(meteo_client);
+                MeteoTabs::Horaire => match meteo_app.mode {
+                    Mode::Read => match key.code {
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            meteo_app.deplacer_horaire(-1);
                         }
-                        KeyCode::Left => {
-                            meteo_app.left();
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            meteo_app.deplacer_horaire(1);
                         }
-                        KeyCode::Right => {
-                            meteo_app.right();
+                        KeyCode::PageUp => {
+                            meteo_app.page_horaire(-1);
                         }
-                        KeyCode::Up => {
-                            meteo_app.up();
+                        KeyCode::PageDown => {
+                            meteo_app.page_horaire(1);
                         }
-                        KeyCode::Down => {
-                            meteo_app.down();
+                        KeyCode::Left | KeyCode::Char('h') => {
+                            meteo_app.jour_horaire(-1);
+                        }
+                        KeyCode::Right | KeyCode::Char('l') => {
+                            meteo_app.jour_horaire(1);
+                        }
+                        KeyCode::Home => {
+                            meteo_app.etat_horaire.select(Some(0));
+                        }
+                        KeyCode::End => {
+                            let derniere = meteo_app.heures().len().saturating_sub(1);
+                            meteo_app.etat_horaire.select(Some(derniere));
                         }
                         KeyCode::Char('q') => {
                             return Ok(());
@@ -555,26 +1467,86 @@ pub fn ui(
                         KeyCode::Char('s') => {
                             meteo_app.search();
                         }
+                        KeyCode::Char(c @ '1'..='9') => {
+                            let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+                            meteo_app.ouvrir_favori(meteo_client, index);
+                        }
+                        KeyCode::Esc => {
+                            meteo_app.esc();
+                        }
                         KeyCode::Tab => {
                             meteo_app.tab_key();
                         }
                         _ => {}
                     },
-                    Mode::InputMode => match key.code {
+                    Mode::Input => meteo_app.touche_recherche(key.code, meteo_client),
+                    // La saisie d'un favori ne se fait que depuis l'onglet Prévisions
+                    Mode::Label => {}
+                },
+                MeteoTabs::Carte => match meteo_app.mode {
+                    Mode::Read => match key.code {
+                        KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                            meteo_app.deplacer_vue(-1.0, 0.0);
+                        }
+                        KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                            meteo_app.deplacer_vue(1.0, 0.0);
+                        }
+                        KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                            meteo_app.deplacer_vue(0.0, 1.0);
+                        }
+                        KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                            meteo_app.deplacer_vue(0.0, -1.0);
+                        }
+                        KeyCode::Char('+') => {
+                            meteo_app.zoomer(ZOOM_PAS);
+                        }
+                        KeyCode::Char('-') => {
+                            meteo_app.zoomer(1.0 / ZOOM_PAS);
+                        }
+                        KeyCode::Enter => match meteo_app.curseur {
+                            Some(_) => meteo_app.prevision_point(meteo_client),
+                            None => meteo_app.select(meteo_client),
+                        },
+                        KeyCode::Left | KeyCode::Char('h') => {
+                            meteo_app.left();
+                        }
+                        KeyCode::Right | KeyCode::Char('l') => {
+                            meteo_app.right();
+                        }
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            meteo_app.up();
+                        }
+                        KeyCode::Down | KeyCode::Char('j') => {
+                            meteo_app.down();
+                        }
+                        KeyCode::Char('c') => {
+                            meteo_app.basculer_curseur();
+                        }
+                        KeyCode::Char('v') => {
+                            meteo_app.variable_carte = meteo_app.variable_carte.suivante();
+                        }
                         KeyCode::Esc => {
-                            meteo_app.esc();
+                            meteo_app.curseur = None;
+                            meteo_app.prevision_curseur = None;
                         }
-                        KeyCode::Enter => {
-                            meteo_app.enter(meteo_client);
+                        KeyCode::Char('q') => {
+                            return Ok(());
                         }
-                        KeyCode::Backspace => {
-                            meteo_app.search.pop();
+                        KeyCode::Char('s') => {
+                            meteo_app.search();
                         }
-                        KeyCode::Char(c) => {
-                            meteo_app.search.push(c);
+                        KeyCode::Char(c @ '1'..='9') => {
+                            let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+                            meteo_app.ouvrir_favori(meteo_client, index);
+                        }
+                        KeyCode::Tab => {
+                            meteo_app.tab_key();
                         }
                         _ => {}
                     },
+                    Mode::Input => meteo_app.touche_recherche(key.code, meteo_client),
+                    // La saisie d'un favori ne se fait que depuis l'onglet Prévisions
+                    Mode::Label => {}
                 },
             }
         }
@@ -612,12 +1584,8 @@ fn draw_fullpage_message(
         }
 
         // Gestion des touches du clavier
-        if let Event::Key(key) = event::read().unwrap() {
-            match key.code {
-                _ => {
-                    return;
-                }
-            }
+        if let Event::Key(_) = event::read().unwrap() {
+            return;
         }
     }
 }
@@ -638,32 +1606,30 @@ fn draw<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp) {
         .split(main_frame[0]);
 
     // Tabs
-    let tabs_names = vec![Spans::from("Carte"), Spans::from("Prévisions")];
+    let tabs_names = NOMS_ONGLETS.iter().map(|n| Spans::from(*n)).collect();
     let block_navigation = Block::default().title("Navigation").borders(Borders::ALL);
     let tabs = Tabs::new(tabs_names)
         .block(block_navigation)
-        .select(match meteo_app.tab {
-            MeteoTabs::TabCarte => 0,
-            MeteoTabs::TabPrevision => 1,
-        })
+        .select(meteo_app.tab.index())
         .highlight_style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
                 .bg(Color::Yellow),
         );
     f.render_widget(tabs, sub_top_frame[0]);
+    meteo_app.zones.onglets = sub_top_frame[0];
 
     // Si touche "s" préssé (mode recherche)
     let block_search = Block::default()
         .title(match meteo_app.search_error {
-            false => "Recherche (code postal)".as_ref(),
+            false => "Recherche (code postal)",
             true => meteo_app.search_error_message.as_ref(),
         })
         .borders(Borders::ALL);
     let search_input = Paragraph::new(meteo_app.search.as_ref())
         .style(match meteo_app.mode {
-            Mode::InputMode => Style::default().fg(Color::Yellow),
-            Mode::ReadMode => match meteo_app.search_error {
+            Mode::Input => Style::default().fg(Color::Yellow),
+            Mode::Read | Mode::Label => match meteo_app.search_error {
                 true => Style::default().fg(Color::Red),
                 false => Style::default(),
             },
@@ -674,25 +1640,163 @@ fn draw<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp) {
     // Le placement du curseur à la fin du texte est une solution
     // trouvée depuis l'exemple "user input" de tui-rs
     match meteo_app.mode {
-        Mode::InputMode => f.set_cursor(
+        Mode::Input => f.set_cursor(
             sub_top_frame[1].x + meteo_app.search.width() as u16 + 1,
             sub_top_frame[1].y + 1,
         ),
-        Mode::ReadMode => {}
+        Mode::Read | Mode::Label => {}
     }
 
     // Selon l'onglet sélectionné, on exécute des fonctions différentes
     match meteo_app.tab {
-        MeteoTabs::TabCarte => onglet_carte(f, meteo_app, main_frame[1]),
-        MeteoTabs::TabPrevision => onglet_prevision(f, meteo_app, main_frame[1]),
+        MeteoTabs::Carte => onglet_carte(f, meteo_app, main_frame[1]),
+        MeteoTabs::Prevision => onglet_prevision(f, meteo_app, main_frame[1]),
+        MeteoTabs::Horaire => onglet_horaire(f, meteo_app, main_frame[1]),
+    }
+
+    // L'historique s'affiche par-dessus le contenu de l'onglet
+    if let Mode::Input = meteo_app.mode {
+        popup_historique(f, meteo_app, sub_top_frame[1])
+    }
+}
+
+/// Fonction pour afficher l'historique des recherches sous le champ de recherche,
+/// avec le nom de la commune trouvée pour chaque recherche
+fn popup_historique<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, search_frame: Rect) {
+    if meteo_app.historique.is_empty() {
+        return;
+    }
+
+    let taille = f.size();
+    let y = search_frame.y + search_frame.height;
+    let hauteur = (meteo_app.historique.len() as u16 + 2).min(taille.height.saturating_sub(y));
+    if hauteur < 3 {
+        return;
+    }
+    let zone = Rect::new(search_frame.x, y, search_frame.width, hauteur);
+
+    let lignes: Vec<Spans> = meteo_app
+        .historique
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let style = if meteo_app.historique_index == Some(i) {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            Spans::from(vec![
+                Span::styled(
+                    format!("{:<8}", r.requete),
+                    style.add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!(" {}", r.commune), style),
+            ])
+        })
+        .collect();
+
+    // On fait défiler la liste pour que la recherche sélectionnée reste visible
+    let visibles = hauteur.saturating_sub(2) as usize;
+    let scroll = match meteo_app.historique_index {
+        Some(i) if i >= visibles => (i + 1 - visibles) as u16,
+        _ => 0,
+    };
+
+    let paragraph = Paragraph::new(Text::from(lignes))
+        .block(
+            Block::default()
+                .title("Historique (↑/↓)")
+                .borders(Borders::ALL),
+        )
+        .scroll((scroll, 0));
+    f.render_widget(Clear, zone);
+    f.render_widget(paragraph, zone);
+}
+
+/// Contour fermé dessiné point à point
+fn dessiner_contour(ctx: &mut Context, contour: &[(f64, f64)], couleur: Color) {
+    for (i, (x1, y1)) in contour.iter().enumerate() {
+        let (x2, y2) = contour[(i + 1) % contour.len()];
+        ctx.draw(&Line {
+            x1: *x1,
+            y1: *y1,
+            x2,
+            y2,
+            color: couleur,
+        });
+    }
+}
+
+/// Icon météo dessiné calque par calque, chaque point étant un petit carré
+fn dessiner_icon(ctx: &mut Context, calques: &[ascii_icons::Calque]) {
+    for calque in calques {
+        for c in &calque.points {
+            ctx.draw(&Rectangle {
+                x: c.0,
+                y: c.1,
+                width: 4.0,
+                height: 4.0,
+                color: calque.couleur,
+            })
+        }
+    }
+}
+
+/// Préfectures à afficher quand la carte est zoomée : celles qui sont dans
+/// la vue, qui ne sont pas déjà des villes de la carte, et dont le nom ne
+/// chevauche aucune autre étiquette. Le point "·" est placé sur la préfecture.
+fn etiquettes_prefectures(meteo_app: &MeteoApp, zone: Rect) -> Vec<(f64, f64, String)> {
+    let vue = &meteo_app.vue;
+    if vue.zoom < ZOOM_PREFECTURES {
+        return vec![];
+    }
+
+    // Cases déjà occupées : (ligne, première colonne, colonne de fin)
+    let largeur = largeur_caractere(zone, vue);
+    let gauche = vue.x_bounds()[0];
+    let mut occupees: Vec<(u16, u16, u16)> = meteo_app
+        .previsions_france
+        .iter()
+        .filter(|p| vue.contient(p.x, p.y))
+        .map(|p| {
+            let debut = p.debut_etiquette(largeur, gauche, meteo_app.symboles);
+            let (colonne, ligne) = position_ecran(zone, vue, debut, p.y);
+            let largeur_etiquette = p.etiquette(false, meteo_app.symboles).width() as u16;
+            (ligne, colonne, colonne + largeur_etiquette)
+        })
+        .collect();
+
+    let mut etiquettes = vec![];
+    for prefecture in &meteo_app.prefectures {
+        let deja_affichee = meteo_app
+            .previsions_france
+            .iter()
+            .any(|p| p.name.to_lowercase() == prefecture.name.to_lowercase());
+        let (x, y) = meteo_app.carte.projeter(prefecture.lat, prefecture.lon);
+        if deja_affichee || !vue.contient(x, y) {
+            continue;
+        }
+        let texte = format!("· {}", prefecture.name);
+        let (colonne, ligne) = position_ecran(zone, vue, x, y);
+        let fin = colonne + texte.width() as u16;
+        let chevauche = occupees
+            .iter()
+            .any(|(l, debut, f)| *l == ligne && colonne <= *f && fin >= *debut);
+        if chevauche || fin > zone.x + zone.width {
+            continue;
+        }
+        occupees.push((ligne, colonne, fin));
+        etiquettes.push((x, y, texte));
     }
+
+    etiquettes
 }
 
 /// Fonction pour afficher l'interface de la carte de France
 fn onglet_carte<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, frame: Rect) {
     let sub_frame = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(2)].as_ref())
+        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
         .horizontal_margin(2)
         .vertical_margin(1)
         .split(frame);
@@ -740,6 +1844,34 @@ fn onglet_carte<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, frame: R
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" pour sélectionner la ville."),
+        Span::styled(
+            " c",
+            Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" pour déplacer un curseur libre (flèches ou h j k l, puis ENTRER)."),
+        Span::styled(
+            " v",
+            Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" pour colorer la carte (températures, précipitations, humidité)."),
+        Span::styled(
+            " + -",
+            Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" pour zoomer et"),
+        Span::styled(
+            " MAJ + flèches",
+            Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" pour déplacer la carte."),
         Span::styled(
             " TAB",
             Style::default()
@@ -765,191 +1897,346 @@ fn onglet_carte<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, frame: R
         .borders(Borders::ALL);
     f.render_widget(block, frame);
 
-    // Block carte France
+    // Block carte France, avec la légende de la variable colorée s'il y en a une
+    let zone_carte = match meteo_app.variable_carte {
+        VariableCarte::Aucune => rect_main[0],
+        variable => {
+            let zones = Layout::default()
+                .constraints([Constraint::Min(0), Constraint::Length(2)].as_ref())
+                .split(rect_main[0]);
+            let legende = Paragraph::new(variable.legende()).wrap(Wrap { trim: true });
+            f.render_widget(legende, zones[1]);
+            zones[0]
+        }
+    };
+    let grille = grille_choroplethe(
+        &meteo_app.previsions_france,
+        &meteo_app.carte,
+        meteo_app.variable_carte,
+        zone_carte,
+        &meteo_app.vue,
+    );
+    let prefectures = etiquettes_prefectures(meteo_app, zone_carte);
+
+    let vue = meteo_app.vue;
+    let largeur_caractere = largeur_caractere(zone_carte, &vue);
     let app_copy = meteo_app.clone();
     let france_canvas = Canvas::default()
         .paint(move |ctx| {
-            let coords = ascii_icons::get_france_coords();
-            for c in coords {
-                // coords: &[*c],
+            if !grille.is_empty() {
+                for (couleur, points) in &grille {
+                    ctx.draw(&Points {
+                        coords: points,
+                        color: *couleur,
+                    });
+                }
+                ctx.layer();
+            }
+
+            // Contours lus depuis les fichiers GeoJSON : les fichiers
+            // secondaires (départements, pays voisins...) sous la France
+            for anneau in &app_copy.carte.contours_secondaires {
+                dessiner_contour(ctx, anneau, Color::DarkGray);
+            }
+            for anneau in &app_copy.carte.contours {
+                dessiner_contour(ctx, anneau, Color::White);
+            }
+
+            // Encarts : un cadre, le contour du territoire (la Corse
+            // fait déjà partie du tracé de la France) et son nom
+            for encart in &app_copy.carte.encarts {
+                let [x_min, y_min, x_max, y_max] = encart.cadre;
                 ctx.draw(&Rectangle {
-                    x: c.0,
-                    y: c.1,
-                    width: 1.0,
-                    height: 1.0,
-                    color: Color::White,
-                })
+                    x: x_min,
+                    y: y_min,
+                    width: x_max - x_min,
+                    height: y_max - y_min,
+                    color: Color::DarkGray,
+                });
+                if !encart.metropole {
+                    dessiner_contour(ctx, &encart.contour, Color::White);
+                }
+                ctx.print(
+                    x_min + largeur_caractere,
+                    y_max,
+                    Span::styled(encart.nom, Style::default().fg(Color::DarkGray)),
+                );
+            }
+
+            // Les favoris et la ville affichée dans l'onglet Prévisions
+            // sont placés sur la carte grâce à la projection
+            let carte = &app_copy.carte;
+            for favori in &app_copy.favoris {
+                match carte.projeter_place(&favori.place) {
+                    Some((x, y)) if carte.contient(x, y) => {
+                        ctx.print(x, y, Span::styled("★", Style::default().fg(Color::Yellow)))
+                    }
+                    _ => {}
+                }
+            }
+            match carte.projeter_place(&app_copy.actual_place) {
+                Some((x, y)) if carte.contient(x, y) => ctx.print(
+                    x,
+                    y,
+                    Span::styled("◆", Style::default().fg(Color::LightCyan)),
+                ),
+                _ => {}
+            }
+
+            for (x, y, nom) in &prefectures {
+                ctx.print(
+                    *x,
+                    *y,
+                    Span::styled(nom.clone(), Style::default().fg(Color::Gray)),
+                );
             }
 
-            let mut i = 0;
             let previsions = app_copy.previsions_france.clone();
             let ville_selected = app_copy.previsions_france_selected;
-            for p in previsions {
-                let _color = if ville_selected == i {
-                    Color::LightMagenta
-                } else {
-                    Color::White
-                };
-
-                ctx.print(p.x.into(), p.y.into(), p.name);
+            let gauche = vue.x_bounds()[0];
+            for (i, p) in previsions.into_iter().enumerate() {
+                if !vue.contient(p.x, p.y) {
+                    continue;
+                }
+                let etiquette = p.etiquette(ville_selected == i as i32, app_copy.symboles);
+                let debut = p.debut_etiquette(largeur_caractere, gauche, app_copy.symboles);
+                ctx.print(debut, p.y, etiquette);
+            }
 
-                i += 1;
+            // Curseur libre, dessiné en forme de croix
+            if let Some((x, y)) = app_copy.curseur {
+                let taille = 4.0 / vue.zoom;
+                ctx.layer();
+                ctx.draw(&Line {
+                    x1: x - taille,
+                    y1: y,
+                    x2: x + taille,
+                    y2: y,
+                    color: Color::LightMagenta,
+                });
+                ctx.draw(&Line {
+                    x1: x,
+                    y1: y - taille,
+                    x2: x,
+                    y2: y + taille,
+                    color: Color::LightMagenta,
+                });
             }
         })
-        .x_bounds([0.0, 170.0])
-        .y_bounds([0.0, 170.0]);
-    f.render_widget(france_canvas, rect_main[0]);
+        .x_bounds(vue.x_bounds())
+        .y_bounds(vue.y_bounds());
+    f.render_widget(france_canvas, zone_carte);
+    meteo_app.zones.carte = zone_carte;
 
     // Block prévision
     // Block meteo now
     let cases_meteo_now = Layout::default()
         .constraints(vec![
             Constraint::Length(1),
-            Constraint::Percentage(70),
-            Constraint::Percentage(30),
+            Constraint::Min(0),
+            Constraint::Length(6),
         ])
         .horizontal_margin(4)
         .vertical_margin(1)
         .split(rect_main[1]);
 
-    let i: usize = meteo_app.previsions_france_selected.try_into().unwrap();
-    let prevs = meteo_app.previsions_france.clone();
-    let prev = prevs[i].prev.to_owned();
+    // En mode curseur libre, on affiche les prévisions du point visé,
+    // sinon celles de la ville sélectionnée
+    let (nom, prev) = match meteo_app.curseur {
+        Some((x, y)) => {
+            let (lat, lon) = meteo_app.carte.inverser(x, y);
+            match meteo_app.prevision_curseur.to_owned() {
+                Some(prev) => {
+                    let nom = match prev.properties.to_owned().and_then(|p| p.name) {
+                        Some(n) => format!("{} ({:.2}, {:.2})", n, lat, lon),
+                        None => format!("{:.2}, {:.2}", lat, lon),
+                    };
+                    (nom, Some(prev))
+                }
+                None => {
+                    let message = Paragraph::new(Text::from(vec![
+                        Spans::from(Span::styled(
+                            format!("{:.2}, {:.2}", lat, lon),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        )),
+                        Spans::from(""),
+                        Spans::from("Pressez ENTRER pour afficher la météo de ce point."),
+                    ]))
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true });
+                    f.render_widget(message, cases_meteo_now[1]);
+                    return;
+                }
+            }
+        }
+        None => {
+            let i: usize = meteo_app.previsions_france_selected.try_into().unwrap();
+            let ville = &meteo_app.previsions_france[i];
+            (ville.name.clone(), ville.prev.to_owned())
+        }
+    };
 
     match prev {
         Some(prev) => {
-            let today_prev = prev
-                .properties
-                .to_owned()
-                .unwrap()
-                .daily_forecast
-                .to_owned()
-                .unwrap()[0]
-                .to_owned();
+            bloc_maintenant(f, meteo_app, &prev, nom, &cases_meteo_now);
+        }
+        None => {
+            let message = Paragraph::new(Text::from(vec![
+                Spans::from(Span::styled(
+                    nom,
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                Spans::from(""),
+                Spans::from("Prévisions indisponibles pour cette ville."),
+            ]))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+            f.render_widget(message, cases_meteo_now[1]);
+        }
+    }
+}
 
-            let date_prevision = Paragraph::new(Text::styled(
-                prevs[i].name,
-                Style::default().add_modifier(Modifier::BOLD),
-            ))
-            .alignment(Alignment::Center);
-            f.render_widget(date_prevision, cases_meteo_now[0]);
-
-            let today_icon = Canvas::default()
-                .paint(|ctx| {
-                    let icon_str = today_prev.daily_weather_icon.to_owned().unwrap();
-                    let coords = &mut ascii_icons::get_coords_icon_from_str(icon_str);
-                    for c in coords {
-                        // coords: &[*c],
-                        ctx.draw(&Rectangle {
-                            x: c.0,
-                            y: c.1,
-                            width: 4.0,
-                            height: 4.0,
-                            color: Color::White,
-                        })
-                    }
-                })
-                .x_bounds([0.0, 160.0])
-                .y_bounds([0.0, 160.0]);
-            f.render_widget(today_icon, cases_meteo_now[1]);
+/// Fonction pour afficher les conditions actuelles d'une prévision (onglets
+/// Carte et Prévisions) : titre, icon, puis le temps, la température et le
+/// ressenti, le vent, l'humidité et les nuages de la prévision heure par
+/// heure la plus proche de maintenant. Les températures minimale et
+/// maximale et les heures du soleil de la journée viennent en dernier.
+fn bloc_maintenant<B: Backend>(
+    f: &mut Frame<B>,
+    meteo_app: &MeteoApp,
+    prev: &Prevision,
+    titre: String,
+    cases: &[Rect],
+) {
+    let properties = match &prev.properties {
+        Some(p) => p,
+        None => return,
+    };
+    let journee = properties
+        .daily_forecast
+        .to_owned()
+        .unwrap_or_default()
+        .first()
+        .cloned()
+        .unwrap_or(DailyForecast::new());
+    let heures = previsions_horaires(prev);
+    let actuelle = index_heure_actuelle(&heures).map(|i| &heures[i]);
 
-            let today_meteo_rect = Layout::default()
-                .constraints(vec![
-                    Constraint::Length(1), // Temps écrit
-                    Constraint::Length(1), // Temperature
-                    Constraint::Length(1), // Humidity
-                    Constraint::Length(1), // Vent
-                ])
-                .horizontal_margin(2)
-                .vertical_margin(0)
-                .split(cases_meteo_now[2]);
+    let titre = match actuelle {
+        Some((dateheure, _)) => format!("{} à {}", titre, dateheure.format("%Hh")),
+        None => titre,
+    };
+    f.render_widget(
+        Paragraph::new(Text::styled(
+            titre,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .alignment(Alignment::Center),
+        cases[0],
+    );
 
-            f.render_widget(
-                Paragraph::new(Text::styled(
-                    today_prev
-                        .daily_weather_description
-                        .to_owned()
-                        .unwrap_or(String::new()),
-                    Style::default().add_modifier(Modifier::BOLD),
-                ))
-                .alignment(Alignment::Center),
-                today_meteo_rect[0],
-            );
+    // Sans prévision heure par heure, on se rabat sur celle de la journée
+    let (icon, description) = match actuelle {
+        Some((_, heure)) => (
+            heure.weather_icon.to_owned(),
+            heure.weather_description.to_owned(),
+        ),
+        None => (
+            journee.daily_weather_icon.to_owned(),
+            journee.daily_weather_description.to_owned(),
+        ),
+    };
+    let calques =
+        ascii_icons::get_calques_icon_from_str(icon.unwrap_or_default(), &meteo_app.icones);
+    let icon_canvas = Canvas::default()
+        .paint(|ctx| dessiner_icon(ctx, &calques))
+        .x_bounds([0.0, 160.0])
+        .y_bounds([0.0, 160.0]);
+    f.render_widget(icon_canvas, cases[1]);
 
-            let today_meteo_temp = vec![
-                Span::raw("↑ "),
-                Span::styled(
-                    today_prev.t_max.to_owned().unwrap_or(0.0).to_string(),
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled("°c ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw("↓ "),
-                Span::styled(
-                    today_prev.t_min.to_owned().unwrap_or(0.0).to_string(),
-                    Style::default()
-                        .fg(Color::LightBlue)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled("°c", Style::default().add_modifier(Modifier::BOLD)),
-            ];
-            f.render_widget(
-                Paragraph::new(Text::from(Spans::from(today_meteo_temp)))
-                    .alignment(Alignment::Center),
-                today_meteo_rect[1],
-            );
+    let style_gras = Style::default().add_modifier(Modifier::BOLD);
+    let style_secondaire = Style::default().fg(Color::Gray);
+    let mut lignes = vec![Spans::from(Span::styled(
+        description.unwrap_or_default(),
+        style_gras,
+    ))];
 
-            let today_meteo_humidity = vec![
-                Span::raw("• "),
-                Span::raw(
-                    today_prev
-                        .relative_humidity_min
-                        .to_owned()
-                        .unwrap_or(0)
-                        .to_string(),
-                ),
-                Span::raw("-"),
-                Span::raw(
-                    today_prev
-                        .relative_humidity_max
-                        .to_owned()
-                        .unwrap_or(0)
-                        .to_string(),
-                ),
-                Span::raw("% humidité"),
-            ];
-            f.render_widget(
-                Paragraph::new(Text::from(Spans::from(today_meteo_humidity)))
-                    .alignment(Alignment::Center),
-                today_meteo_rect[2],
-            );
+    if let Some((_, heure)) = actuelle {
+        // Température et ressenti
+        let mut temperature = vec![];
+        if let Some(t) = heure.t {
+            temperature.push(Span::styled(
+                format!("{}°c", t),
+                style_gras.fg(couleur_temperature(t)),
+            ))
+        }
+        if let Some(ressenti) = heure.t_windchill {
+            temperature.push(Span::raw(format!(" (ressenti {}°c)", ressenti)))
+        }
+        lignes.push(Spans::from(temperature));
 
-            let sunrise_time =
-                DateHeure::get_dateheure_from_str(today_prev.sunrise_time.to_owned().unwrap());
-            let sunset_time =
-                DateHeure::get_dateheure_from_str(today_prev.sunset_time.to_owned().unwrap());
-            let today_meteo_sun = vec![
-                Span::styled("☀ ", Style::default().fg(Color::Yellow)),
-                Span::styled(
-                    sunrise_time.format("%Hh%M").to_string(),
-                    Style::default().fg(Color::Yellow),
-                ),
-                Span::raw(" - "),
-                Span::styled("☾ ", Style::default().fg(Color::LightCyan)),
-                Span::styled(
-                    sunset_time.format("%Hh%M").to_string(),
-                    Style::default().fg(Color::LightCyan),
-                ),
-            ];
-            f.render_widget(
-                Paragraph::new(Text::from(Spans::from(today_meteo_sun)))
-                    .alignment(Alignment::Center),
-                today_meteo_rect[3],
-            );
+        // Vent
+        let mut vent = vec![Span::raw("Vent ")];
+        if let Some(d) = heure.wind_direction {
+            vent.push(Span::raw(format!("{} ", fleche_vent(d))))
         }
-        None => {}
+        let vitesse = vitesse_kmh(heure.wind_speed.unwrap_or(0.0));
+        vent.push(Span::styled(
+            format!("{:.0} km/h", vitesse),
+            Style::default().fg(couleur_vent(vitesse)),
+        ));
+        match heure.wind_speed_gust {
+            Some(rafales) if rafales > 0.0 => {
+                vent.push(Span::raw(format!(" (raf. {:.0})", vitesse_kmh(rafales))))
+            }
+            _ => {}
+        }
+        lignes.push(Spans::from(vent));
+
+        // Humidité et nuages
+        let mut air = vec![];
+        if let Some(h) = heure.relative_humidity {
+            air.push(Span::raw(format!("• {}% humidité", h)))
+        }
+        if let Some(n) = heure.total_cloud_cover {
+            air.push(Span::raw(format!("  ☁ {}% nuages", n)))
+        }
+        lignes.push(Spans::from(air));
+    }
+
+    // Journée : températures extrêmes et soleil
+    lignes.push(Spans::from(vec![
+        Span::styled("Journée ↑ ", style_secondaire),
+        Span::styled(
+            format!("{}°c", journee.t_max.unwrap_or(0.0)),
+            Style::default().fg(Color::Green),
+        ),
+        Span::styled(" ↓ ", style_secondaire),
+        Span::styled(
+            format!("{}°c", journee.t_min.unwrap_or(0.0)),
+            Style::default().fg(Color::LightBlue),
+        ),
+    ]));
+    if let (Some(lever), Some(coucher)) = (&journee.sunrise_time, &journee.sunset_time) {
+        let lever = get_dateheure_locale(lever.to_owned(), &properties.timezone);
+        let coucher = get_dateheure_locale(coucher.to_owned(), &properties.timezone);
+        lignes.push(Spans::from(vec![
+            Span::styled("☀ ", Style::default().fg(Color::Yellow)),
+            Span::styled(lever.format("%Hh%M").to_string(), style_secondaire),
+            Span::raw(" - "),
+            Span::styled("☾ ", Style::default().fg(Color::LightCyan)),
+            Span::styled(coucher.format("%Hh%M").to_string(), style_secondaire),
+        ]));
     }
+
+    f.render_widget(
+        Paragraph::new(lignes).alignment(Alignment::Center),
+        Rect {
+            x: cases[2].x + 2,
+            width: cases[2].width.saturating_sub(4),
+            ..cases[2]
+        },
+    );
 }
 
 /// Fonction pour afficher l'interface des prévisions météo d'une ville
@@ -963,7 +2250,14 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
 
             let rect_sub_left = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+                .constraints(
+                    [
+                        Constraint::Percentage(55),
+                        Constraint::Percentage(20),
+                        Constraint::Percentage(25),
+                    ]
+                    .as_ref(),
+                )
                 .split(rect_main[0]);
 
             let rect_sub_right = Layout::default()
@@ -977,8 +2271,8 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
             let cases_meteo_now = Layout::default()
                 .constraints(vec![
                     Constraint::Length(1),
-                    Constraint::Percentage(70),
-                    Constraint::Percentage(30),
+                    Constraint::Min(0),
+                    Constraint::Length(6),
                 ])
                 .horizontal_margin(4)
                 .vertical_margin(1)
@@ -1021,6 +2315,41 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("pour changer la ville au démarrage, "),
+                Span::styled(
+                    "a ",
+                    Style::default()
+                        .fg(Color::LightMagenta)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("/ "),
+                Span::styled(
+                    "d ",
+                    Style::default()
+                        .fg(Color::LightMagenta)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("pour ajouter / retirer un favori, "),
+                Span::styled(
+                    "1-9 ",
+                    Style::default()
+                        .fg(Color::LightMagenta)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("pour ouvrir un favori, "),
+                Span::styled(
+                    "← → ",
+                    Style::default()
+                        .fg(Color::LightMagenta)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("puis "),
+                Span::styled(
+                    "ENTRER ",
+                    Style::default()
+                        .fg(Color::LightMagenta)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("pour le détail d'un jour, "),
                 Span::styled(
                     "TAB",
                     Style::default()
@@ -1040,147 +2369,31 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
             let help_paragraph = Paragraph::new(Text::from(Spans::from(help_text)))
                 .block(Block::default().title("Aide").borders(Borders::ALL))
                 .wrap(Wrap { trim: true });
-            f.render_widget(help_paragraph, rect_sub_left[1]);
+            f.render_widget(help_paragraph, rect_sub_left[2]);
+
+            bloc_favoris(f, meteo_app, rect_sub_left[1]);
 
             let block_meteo_jours = Block::default()
                 .title("Prévisions".as_ref())
                 .borders(Borders::ALL);
             f.render_widget(block_meteo_jours, rect_sub_right[0]);
 
-            let block_meteo_temperatures = Block::default()
-                .title("Températures".as_ref())
-                .borders(Borders::ALL);
-            f.render_widget(block_meteo_temperatures, rect_sub_right[1]);
-
-            let today_prev = prev
-                .properties
-                .to_owned()
-                .unwrap()
-                .daily_forecast
-                .to_owned()
-                .unwrap()[0]
-                .to_owned();
-
-            let date_prevision = Paragraph::new(Text::styled(
-                "Aujourd'hui",
-                Style::default().add_modifier(Modifier::BOLD),
-            ))
-            .alignment(Alignment::Center);
-            f.render_widget(date_prevision, cases_meteo_now[0]);
-
-            let today_icon = Canvas::default()
-                .paint(|ctx| {
-                    let icon_str = today_prev.daily_weather_icon.to_owned().unwrap();
-                    let coords = &mut ascii_icons::get_coords_icon_from_str(icon_str);
-                    for c in coords {
-                        // coords: &[*c],
-                        ctx.draw(&Rectangle {
-                            x: c.0,
-                            y: c.1,
-                            width: 4.0,
-                            height: 4.0,
-                            color: Color::White,
-                        })
-                    }
-                })
-                .x_bounds([0.0, 160.0])
-                .y_bounds([0.0, 160.0]);
-            f.render_widget(today_icon, cases_meteo_now[1]);
-
-            let today_meteo_rect = Layout::default()
-                .constraints(vec![
-                    Constraint::Length(1), // Temps écrit
-                    Constraint::Length(1), // Temperature
-                    Constraint::Length(1), // Humidity
-                    Constraint::Length(1), // Vent
-                ])
-                .horizontal_margin(2)
-                .vertical_margin(0)
-                .split(cases_meteo_now[2]);
-
-            f.render_widget(
-                Paragraph::new(Text::styled(
-                    today_prev
-                        .daily_weather_description
-                        .to_owned()
-                        .unwrap_or(String::new()),
-                    Style::default().add_modifier(Modifier::BOLD),
-                ))
-                .alignment(Alignment::Center),
-                today_meteo_rect[0],
-            );
-
-            let today_meteo_temp = vec![
-                Span::raw("↑ "),
-                Span::styled(
-                    today_prev.t_max.to_owned().unwrap_or(0.0).to_string(),
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled("°c ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw("↓ "),
-                Span::styled(
-                    today_prev.t_min.to_owned().unwrap_or(0.0).to_string(),
-                    Style::default()
-                        .fg(Color::LightBlue)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled("°c", Style::default().add_modifier(Modifier::BOLD)),
-            ];
-            f.render_widget(
-                Paragraph::new(Text::from(Spans::from(today_meteo_temp)))
-                    .alignment(Alignment::Center),
-                today_meteo_rect[1],
+            bloc_maintenant(
+                f,
+                meteo_app,
+                &prev,
+                String::from("Aujourd'hui"),
+                &cases_meteo_now,
             );
 
-            let today_meteo_humidity = vec![
-                Span::raw("• "),
-                Span::raw(
-                    today_prev
-                        .relative_humidity_min
-                        .to_owned()
-                        .unwrap_or(0)
-                        .to_string(),
-                ),
-                Span::raw("-"),
-                Span::raw(
-                    today_prev
-                        .relative_humidity_max
-                        .to_owned()
-                        .unwrap_or(0)
-                        .to_string(),
-                ),
-                Span::raw("% humidité"),
-            ];
-            f.render_widget(
-                Paragraph::new(Text::from(Spans::from(today_meteo_humidity)))
-                    .alignment(Alignment::Center),
-                today_meteo_rect[2],
-            );
-
-            let sunrise_time =
-                DateHeure::get_dateheure_from_str(today_prev.sunrise_time.to_owned().unwrap());
-            let sunset_time =
-                DateHeure::get_dateheure_from_str(today_prev.sunset_time.to_owned().unwrap());
-            let today_meteo_sun = vec![
-                Span::styled("☀ ", Style::default().fg(Color::Yellow)),
-                Span::styled(
-                    sunrise_time.format("%Hh%M").to_string(),
-                    Style::default().fg(Color::Yellow),
-                ),
-                Span::raw(" - "),
-                Span::styled("☾ ", Style::default().fg(Color::LightCyan)),
-                Span::styled(
-                    sunset_time.format("%Hh%M").to_string(),
-                    Style::default().fg(Color::LightCyan),
-                ),
-            ];
-            f.render_widget(
-                Paragraph::new(Text::from(Spans::from(today_meteo_sun)))
-                    .alignment(Alignment::Center),
-                today_meteo_rect[3],
-            );
+            // Jour déplié (clic ou ENTRER sur une carte) : détail de la
+            // journée à la place des cartes et des graphiques
+            if let Some(jour) = meteo_app.jour_deplie {
+                meteo_app.zones.cartes_jours.clear();
+                f.render_widget(Clear, rect_main[1]);
+                bloc_jour_deplie(f, meteo_app, &prev, jour, rect_main[1]);
+                return;
+            }
 
             // Block météo prochains jours (rect $rect_sub_right)
             let cases_daily_forecast = Layout::default()
@@ -1194,7 +2407,14 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
                 .margin(2)
                 .split(rect_sub_right[0]);
 
-            for i in 0..8 {
+            // Une carte par jour disponible, à partir du lendemain
+            let journees = prev
+                .properties
+                .as_ref()
+                .and_then(|p| p.daily_forecast.to_owned())
+                .unwrap_or_default();
+            meteo_app.zones.cartes_jours.clear();
+            for i in 0..meteo_app.nombre_cartes_jours() {
                 let case_frame = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints(
@@ -1212,14 +2432,21 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
 
                 let col = if i < 4 { 0 } else { 3 };
 
-                let today_prev = prev
-                    .properties
-                    .to_owned()
-                    .unwrap()
-                    .daily_forecast
-                    .to_owned()
-                    .unwrap()[i + 1]
-                    .to_owned();
+                // Zone de la carte du jour, pour le clic de la souris
+                meteo_app.zones.cartes_jours.push(Rect {
+                    x: case_frame[col].x,
+                    y: case_frame[col].y,
+                    width: case_frame[col].width,
+                    height: (case_frame[col + 2].y + case_frame[col + 2].height)
+                        .saturating_sub(case_frame[col].y),
+                });
+                let couleur_date = if meteo_app.jour_selectionne == i {
+                    Color::Yellow
+                } else {
+                    Color::White
+                };
+
+                let today_prev = journees[i + 1].to_owned();
 
                 let str_date_prevision =
                     get_dateheure_from_str(today_prev.time.to_owned().unwrap_or(String::new()));
@@ -1227,7 +2454,7 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
                     str_date_prevision.format("%d/%m").to_string(),
                     Style::default()
                         .fg(Color::Black)
-                        .bg(Color::White)
+                        .bg(couleur_date)
                         .add_modifier(Modifier::BOLD),
                 ))
                 .alignment(Alignment::Center);
@@ -1243,12 +2470,23 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
                     .horizontal_margin(2)
                     .split(case_frame[col + 2]);
 
+                let icon = ascii_icons::get_type_icon_from_str(
+                    today_prev
+                        .daily_weather_icon
+                        .to_owned()
+                        .unwrap_or_default()
+                        .as_str(),
+                );
                 f.render_widget(
                     Paragraph::new(Text::styled(
-                        today_prev
-                            .daily_weather_description
-                            .to_owned()
-                            .unwrap_or(String::new()),
+                        format!(
+                            "{} {}",
+                            ascii_icons::get_glyphe_aligne(icon, meteo_app.symboles),
+                            today_prev
+                                .daily_weather_description
+                                .to_owned()
+                                .unwrap_or(String::new())
+                        ),
                         Style::default().add_modifier(Modifier::BOLD),
                     )),
                     today_meteo_rect[0],
@@ -1324,53 +2562,1702 @@ fn onglet_prevision<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, fram
                 );
             }
 
-            // Block Températures (rect_sub_right)
-            let mut daily_forecast_vec =
-                prev.properties.unwrap().daily_forecast.to_owned().unwrap();
-            daily_forecast_vec.remove(0);
+            // Blocks Températures et Vent (rect_sub_right)
+            let rect_bas = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
+                .split(rect_sub_right[1]);
+            bloc_vent(f, &previsions_horaires(&prev), rect_bas[1]);
 
-            let mut data_string: Vec<(String, u64)> = vec![];
-            for d in daily_forecast_vec {
-                let mut temp = 0.0;
-                let t_min = d.t_min.unwrap_or(99.1);
-                let t_max = d.t_min.unwrap_or(99.1);
+            graphique_temperatures(f, journees.get(1..).unwrap_or_default(), rect_bas[0]);
+        }
+        None => {
+            let message = "Aucune ville de renseignée ou de recherchée";
+            let block_no_place = Paragraph::new(message).style(Style::default());
+            f.render_widget(block_no_place, frame)
+        }
+    }
+}
 
-                if t_min > 99.0 && t_max > 99.0 {
-                    continue;
-                } else if t_min > 99.0 {
-                    temp = t_max;
-                } else if t_max > 99.0 {
-                    temp = t_min;
-                } else {
-                    temp = (t_min + t_max) / 2.0;
+/// Fonction pour afficher l'écart entre les températures minimales et
+/// maximales de chaque jour : une barre colorée selon la température, les
+/// extrêmes écrits au-dessus et en dessous, et un trait sur zéro degré
+fn graphique_temperatures<B: Backend>(f: &mut Frame<B>, jours: &[DailyForecast], zone: Rect) {
+    let bloc = Block::default()
+        .title("Températures minimales et maximales (en °c)")
+        .borders(Borders::ALL);
+    let interieur = bloc.inner(zone);
+    f.render_widget(bloc, zone);
+
+    let extremes: Vec<(Option<f64>, Option<f64>)> =
+        jours.iter().map(|d| (d.t_min, d.t_max)).collect();
+    let valeurs: Vec<f64> = extremes
+        .iter()
+        .flat_map(|(min, max)| vec![*min, *max])
+        .flatten()
+        .collect();
+    if valeurs.is_empty() || interieur.width == 0 || interieur.height < 4 {
+        return;
+    }
+
+    // Deux lignes de marge au-dessus et en dessous pour écrire les extrêmes
+    let t_min = valeurs.iter().cloned().fold(f64::INFINITY, f64::min);
+    let t_max = valeurs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let hauteur = interieur.height - 1;
+    let ligne = (t_max - t_min).max(1.0) / (hauteur as f64 - 4.0).max(1.0);
+    let y_min = t_min - 2.0 * ligne;
+    let y_max = t_max + 2.0 * ligne;
+
+    let nombre = jours.len();
+    let cases_par_jour = interieur.width as f64 / nombre as f64;
+    let demi_largeur = (1.0 / cases_par_jour).min(0.15);
+    let pas = 1.0 / (cases_par_jour * 4.0);
+    // Abscisse de départ d'un texte centré sur le jour i
+    let centrer = |i: usize, texte: &str| -> f64 {
+        i as f64 + 0.5 - (texte.width() as f64 / 2.0) / cases_par_jour
+    };
+
+    let canvas = Canvas::default()
+        .paint(|ctx| {
+            if y_min < 0.0 && y_max > 0.0 {
+                ctx.draw(&Line {
+                    x1: 0.0,
+                    y1: 0.0,
+                    x2: nombre as f64,
+                    y2: 0.0,
+                    color: Color::DarkGray,
+                });
+            }
+            ctx.layer();
+
+            for (i, (min, max)) in extremes.iter().enumerate() {
+                let (bas, haut) = match (min, max) {
+                    (Some(min), Some(max)) => (*min, *max),
+                    (Some(t), None) | (None, Some(t)) => (*t, *t),
+                    (None, None) => continue,
+                };
+                // Barre découpée par degré pour suivre l'échelle des couleurs
+                let mut t = bas;
+                while t <= haut {
+                    let fin = (t + 1.0).min(haut);
+                    let mut x = i as f64 + 0.5 - demi_largeur;
+                    while x <= i as f64 + 0.5 + demi_largeur {
+                        ctx.draw(&Line {
+                            x1: x,
+                            y1: t,
+                            x2: x,
+                            y2: fin,
+                            color: couleur_temperature((t + fin) / 2.0),
+                        });
+                        x += pas;
+                    }
+                    if fin >= haut {
+                        break;
+                    }
+                    t = fin;
                 }
 
-                let date = get_dateheure_from_str(d.time.to_owned().unwrap());
-                let string_date = date.format("%d/%m").to_string();
-                data_string.push((string_date, temp as u64))
+                if let Some(max) = max {
+                    let texte = format!("{:.0}°", max.round() + 0.0);
+                    ctx.print(
+                        centrer(i, &texte),
+                        max + 1.5 * ligne,
+                        Span::styled(
+                            texte,
+                            Style::default()
+                                .fg(couleur_temperature(*max))
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    );
+                }
+                if let Some(min) = min {
+                    let texte = format!("{:.0}°", min.round() + 0.0);
+                    ctx.print(
+                        centrer(i, &texte),
+                        min - 1.5 * ligne,
+                        Span::styled(texte, Style::default().fg(couleur_temperature(*min))),
+                    );
+                }
             }
+        })
+        .x_bounds([0.0, nombre as f64])
+        .y_bounds([y_min, y_max]);
+    f.render_widget(
+        canvas,
+        Rect::new(interieur.x, interieur.y, interieur.width, hauteur),
+    );
 
-            // Expliquer le gros casse-tête ici
-            // Cf. https://stackoverflow.com/questions/55931401/why-cant-i-return-a-vecstr-from-a-function
-            let data: Vec<(&str, u64)> = data_string.iter().map(|(n, d)| (&**n, *d)).collect();
+    // Dates sous les barres
+    let mut dates = vec![];
+    let mut fin_derniere = 0;
+    for (i, jour) in jours.iter().enumerate() {
+        let date = get_dateheure_from_str(jour.time.to_owned().unwrap_or_default());
+        let texte = if cases_par_jour >= 8.0 {
+            format!("{} {}", get_nom_jour(date.weekday()), date.format("%d"))
+        } else {
+            date.format("%d").to_string()
+        };
+        let debut = ((centrer(i, &texte) * cases_par_jour).max(0.0) as usize).max(fin_derniere);
+        dates.push(Span::raw(" ".repeat(debut - fin_derniere)));
+        fin_derniere = debut + texte.width();
+        dates.push(Span::styled(texte, Style::default().fg(Color::Gray)));
+    }
+    f.render_widget(
+        Paragraph::new(Spans::from(dates)),
+        Rect::new(interieur.x, interieur.y + hauteur, interieur.width, 1),
+    );
+}
 
-            let barchart = BarChart::default()
-                .block(
-                    Block::default()
-                        .title("Températures moyennes (en °c)")
-                        .borders(Borders::ALL),
-                )
-                .data(data.as_slice())
-                .bar_width(10)
-                .bar_gap(3)
-                .bar_style(Style::default().fg(Color::Yellow))
-                .value_style(Style::default().fg(Color::Black).bg(Color::Yellow));
-            f.render_widget(barchart, rect_sub_right[1]);
+/// Fonction pour afficher la liste des favoris (onglet Prévisions).
+/// Le favori affiché est mis en valeur, la ville par défaut est marquée d'une étoile.
+fn bloc_favoris<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, frame: Rect) {
+    let actuel = meteo_app.index_favori_actuel();
+    let mut lignes = vec![];
+    for (i, favori) in meteo_app.favoris.iter().enumerate() {
+        let style = if actuel == Some(i) {
+            Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let mut ligne = vec![
+            Span::styled(
+                format!("{} ", i + 1),
+                Style::default()
+                    .fg(Color::LightMagenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(favori.label.clone(), style),
+            Span::raw(format!(
+                " ({})",
+                favori.place.name.to_owned().unwrap_or(String::new())
+            )),
+        ];
+        if favori.defaut {
+            ligne.push(Span::styled(" ★", Style::default().fg(Color::Yellow)));
         }
+        lignes.push(Spans::from(ligne));
+    }
+
+    if let Mode::Label = meteo_app.mode {
+        let invite = "Nom du favori : ";
+        lignes.push(Spans::from(vec![
+            Span::raw(invite),
+            Span::styled(
+                meteo_app.favori_label.clone(),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]));
+        let y = frame.y + lignes.len() as u16;
+        if y < frame.y + frame.height.saturating_sub(1) {
+            f.set_cursor(
+                frame.x + 1 + (invite.width() + meteo_app.favori_label.width()) as u16,
+                y,
+            );
+        }
+    }
+
+    let paragraph = Paragraph::new(Text::from(lignes))
+        .block(Block::default().title("Favoris").borders(Borders::ALL));
+    f.render_widget(paragraph, frame);
+}
+
+/// L'heure d'une prévision est-elle avant le lever ou après le coucher
+/// du soleil de la journée ? (None si l'API ne donne pas ces heures)
+fn est_de_nuit(heure: String, journee: &DailyForecast) -> Option<bool> {
+    let instant = get_dateheure_from_str(heure);
+    let lever = get_dateheure_from_str(journee.sunrise_time.to_owned()?);
+    let coucher = get_dateheure_from_str(journee.sunset_time.to_owned()?);
+    Some(instant < lever || instant >= coucher)
+}
+
+/// Fonction pour afficher le détail d'un jour (clic ou ENTRER sur une
+/// carte) : résumé de la journée (pluie totale, rafale maximale, indice UV,
+/// température de la mer, durée du jour), risques par créneau et prévisions
+/// heure par heure du jour dans le fuseau du lieu.
+/// La molette de la souris et les flèches "↑" "↓" font défiler les heures.
+fn bloc_jour_deplie<B: Backend>(
+    f: &mut Frame<B>,
+    meteo_app: &mut MeteoApp,
+    prev: &Prevision,
+    jour: usize,
+    frame: Rect,
+) {
+    let properties = match prev.properties.to_owned() {
+        Some(p) => p,
+        None => return,
+    };
+    let journee = match properties.daily_forecast.unwrap_or_default().get(jour) {
+        Some(d) => d.to_owned(),
+        None => return,
+    };
+    let date = get_dateheure_from_str(journee.time.to_owned().unwrap_or_default()).date_naive();
+    let heures: Vec<(DateTime<FixedOffset>, Forecast)> = previsions_horaires(prev)
+        .into_iter()
+        .filter(|(dateheure, _)| dateheure.date_naive() == date)
+        .collect();
+    let creneaux: Vec<(DateTime<FixedOffset>, ProbabilityForecast)> = creneaux_risques(prev)
+        .into_iter()
+        .filter(|(debut, _)| debut.date_naive() == date)
+        .collect();
+
+    let bloc = Block::default()
+        .title(format!(
+            "{} {} (← → pour changer de jour, ÉCHAP pour fermer)",
+            get_nom_jour(date.weekday()),
+            date.format("%d/%m")
+        ))
+        .borders(Borders::ALL);
+    let interieur = bloc.inner(frame);
+    f.render_widget(bloc, frame);
+    let zones = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(4),
+                Constraint::Length(if creneaux.is_empty() { 0 } else { 7 }),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(interieur);
+
+    // Résumé de la journée
+    let icon = ascii_icons::get_type_icon_from_str(
+        journee
+            .daily_weather_icon
+            .to_owned()
+            .unwrap_or_default()
+            .as_str(),
+    );
+    // Cumul donné par l'API, sinon somme des précipitations des heures du jour
+    let pluie = journee.total_precipitation_24h.or_else(|| {
+        let cumuls: Vec<f64> = heures
+            .iter()
+            .filter_map(|(_, h)| h.rain_1h.or(h.rain_3h).or(h.rain_6h))
+            .collect();
+        if cumuls.is_empty() {
+            None
+        } else {
+            Some(cumuls.iter().sum())
+        }
+    });
+    let rafale_max = heures
+        .iter()
+        .filter_map(|(_, h)| h.wind_speed_gust)
+        .fold(None, |max: Option<f64>, r| {
+            Some(max.map_or(r, |m| m.max(r)))
+        });
+    let optionnel = |valeur: Option<f64>, format: &dyn Fn(f64) -> String| match valeur {
+        Some(v) => format(v),
+        None => String::from("-"),
+    };
+    let soleil = match (&journee.sunrise_time, &journee.sunset_time) {
+        (Some(lever), Some(coucher)) => {
+            let lever = get_dateheure_locale(lever.to_owned(), &properties.timezone);
+            let coucher = get_dateheure_locale(coucher.to_owned(), &properties.timezone);
+            let duree = coucher - lever;
+            format!(
+                "☀ {} - ☾ {} ({} h {:02} min de jour)",
+                lever.format("%Hh%M"),
+                coucher.format("%Hh%M"),
+                duree.num_hours(),
+                duree.num_minutes() % 60
+            )
+        }
+        _ => String::from("Heures du soleil inconnues"),
+    };
+    let resume = vec![
+        Spans::from(vec![
+            Span::styled(
+                format!(
+                    "{} {}",
+                    ascii_icons::get_glyphe_aligne(icon, meteo_app.symboles),
+                    journee
+                        .daily_weather_description
+                        .to_owned()
+                        .unwrap_or_default()
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  ↑ "),
+            Span::styled(
+                format!("{}°c", journee.t_max.unwrap_or(0.0)),
+                Style::default().fg(Color::Green),
+            ),
+            Span::raw(" ↓ "),
+            Span::styled(
+                format!("{}°c", journee.t_min.unwrap_or(0.0)),
+                Style::default().fg(Color::LightBlue),
+            ),
+        ]),
+        Spans::from(format!(
+            "Pluie : {}  •  Rafales jusqu'à : {}  •  Indice UV : {}",
+            optionnel(pluie, &|p| format!("{:.1} mm", p)),
+            optionnel(rafale_max, &|r| format!("{:.0} km/h", vitesse_kmh(r))),
+            optionnel(journee.uv_index, &|uv| format!("{:.0}", uv)),
+        )),
+        Spans::from(format!(
+            "Mer : {}  •  {}",
+            optionnel(journee.t_sea, &|t| format!("{:.1}°c", t)),
+            soleil
+        )),
+    ];
+    f.render_widget(Paragraph::new(resume).wrap(Wrap { trim: true }), zones[0]);
+
+    // Risques par créneau de 3 heures, tous les pourcentages écrits
+    if !creneaux.is_empty() {
+        tableau_risques(f, &creneaux, None, None, zones[1]);
+    }
+
+    // Heure par heure
+    let mut lignes = vec![];
+    for (dateheure, heure) in &heures {
+        let code = heure.weather_icon.to_owned().unwrap_or_default();
+        let icon = match est_de_nuit(heure.time.to_owned().unwrap_or_default(), &journee) {
+            Some(nuit) => ascii_icons::get_type_icon_moment(&code, nuit),
+            None => ascii_icons::get_type_icon_from_str(&code),
+        };
+        let vent = match (heure.wind_direction, heure.wind_speed) {
+            (Some(d), Some(v)) => format!("{} {:>3.0} km/h", fleche_vent(d), vitesse_kmh(v)),
+            (None, Some(v)) => format!("  {:>3.0} km/h", vitesse_kmh(v)),
+            _ => String::from("          "),
+        };
+        lignes.push(Spans::from(vec![
+            Span::styled(
+                dateheure.format("%Hh%M  ").to_string(),
+                Style::default().fg(Color::LightMagenta),
+            ),
+            Span::raw(format!(
+                "{}  ",
+                ascii_icons::get_glyphe_aligne(icon, meteo_app.symboles)
+            )),
+            Span::styled(
+                format!("{:>5.1}°c  ", heure.t.unwrap_or(0.0)),
+                Style::default().fg(couleur_temperature(heure.t.unwrap_or(0.0))),
+            ),
+            Span::raw(format!("{}  ", vent)),
+            Span::styled(
+                format!(
+                    "{:>4}  ",
+                    optionnel(intensite_pluie(heure), &|p| format!("{:.1}", p))
+                ),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw(heure.weather_description.to_owned().unwrap_or_default()),
+        ]));
+    }
+    if lignes.is_empty() {
+        lignes.push(Spans::from(
+            "Pas de prévisions heure par heure pour ce jour.",
+        ));
+    }
+
+    // On ne fait pas défiler au-delà de la dernière heure
+    let visibles = zones[2].height.saturating_sub(2) as usize;
+    let maximum = lignes.len().saturating_sub(visibles) as u16;
+    meteo_app.defilement_horaire = meteo_app.defilement_horaire.min(maximum);
+
+    let paragraph = Paragraph::new(Text::from(lignes))
+        .block(
+            Block::default()
+                .title("Heure par heure (pluie en mm/h, molette ou ↑ ↓ pour défiler)")
+                .borders(Borders::ALL),
+        )
+        .scroll((meteo_app.defilement_horaire, 0));
+    f.render_widget(paragraph, zones[2]);
+}
+
+/// Prévisions heure par heure d'une prévision, avec leur date et heure
+/// dans le fuseau du lieu
+fn previsions_horaires(prev: &Prevision) -> Vec<(DateTime<FixedOffset>, Forecast)> {
+    let properties = match &prev.properties {
+        Some(p) => p,
+        None => return vec![],
+    };
+    properties
+        .forecast
+        .to_owned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|heure| {
+            let dateheure = get_dateheure_locale(
+                heure.time.to_owned().unwrap_or_default(),
+                &properties.timezone,
+            );
+            (dateheure, heure)
+        })
+        .collect()
+}
+
+/// Conversion d'une vitesse de l'API (en m/s) en km/h
+fn vitesse_kmh(vitesse: f64) -> f64 {
+    vitesse * 3.6
+}
+
+/// Flèche indiquant vers où souffle le vent. L'API donne la direction
+/// d'où il vient, en degrés (0 : vent du nord, qui souffle vers le sud).
+fn fleche_vent(direction: f64) -> &'static str {
+    const FLECHES: [&str; 8] = ["↓", "↙", "←", "↖", "↑", "↗", "→", "↘"];
+    let secteur = ((direction.rem_euclid(360.0) + 22.5) / 45.0) as usize % 8;
+    FLECHES[secteur]
+}
+
+/// Prévision heure par heure la plus proche de l'heure actuelle
+fn index_heure_actuelle(heures: &[(DateTime<FixedOffset>, Forecast)]) -> Option<usize> {
+    let maintenant = chrono::Utc::now().timestamp();
+    heures
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, (dateheure, _))| (dateheure.timestamp() - maintenant).abs())
+        .map(|(i, _)| i)
+}
+
+/// Nom de la direction d'où vient le vent (rose des vents à 16 directions)
+fn nom_direction_vent(direction: f64) -> &'static str {
+    const DIRECTIONS: [&str; 16] = [
+        "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSO", "SO", "OSO", "O", "ONO",
+        "NO", "NNO",
+    ];
+    let secteur = ((direction.rem_euclid(360.0) + 11.25) / 22.5) as usize % 16;
+    DIRECTIONS[secteur]
+}
+
+/// Force du vent sur l'échelle de Beaufort et son nom, d'après sa vitesse en km/h
+fn beaufort(vitesse: f64) -> (u8, &'static str) {
+    const ECHELLE: [(f64, &str); 12] = [
+        (1.0, "calme"),
+        (6.0, "très légère brise"),
+        (12.0, "légère brise"),
+        (20.0, "petite brise"),
+        (29.0, "jolie brise"),
+        (39.0, "bonne brise"),
+        (50.0, "vent frais"),
+        (62.0, "grand frais"),
+        (75.0, "coup de vent"),
+        (89.0, "fort coup de vent"),
+        (103.0, "tempête"),
+        (118.0, "violente tempête"),
+    ];
+    for (force, (limite, nom)) in ECHELLE.iter().enumerate() {
+        if vitesse < *limite {
+            return (force as u8, nom);
+        }
+    }
+    (12, "ouragan")
+}
+
+/// Fonction pour afficher le vent actuel : rose des vents avec le sens du
+/// vent (vers où il souffle) et ses rafales, direction, vitesse, force sur
+/// l'échelle de Beaufort, puis les directions des 24 prochaines heures
+fn bloc_vent<B: Backend>(
+    f: &mut Frame<B>,
+    heures: &[(DateTime<FixedOffset>, Forecast)],
+    zone: Rect,
+) {
+    let bloc = Block::default().borders(Borders::ALL);
+    let interieur = bloc.inner(zone);
+    let actuelle = match index_heure_actuelle(heures) {
+        Some(i) => i,
+        None => {
+            f.render_widget(bloc.title("Vent"), zone);
+            f.render_widget(Paragraph::new("Pas de prévision du vent."), interieur);
+            return;
+        }
+    };
+    let (dateheure, heure) = &heures[actuelle];
+    f.render_widget(
+        bloc.title(format!("Vent à {}", dateheure.format("%Hh"))),
+        zone,
+    );
+    if interieur.height < 4 {
+        return;
+    }
+    let vitesse = vitesse_kmh(heure.wind_speed.unwrap_or(0.0));
+    let rafales = heure.wind_speed_gust.map(vitesse_kmh).unwrap_or(0.0);
+
+    let zones = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(2)].as_ref())
+        .split(interieur);
+    // Rose des vents à peu près ronde : une case est deux fois plus haute que large
+    let largeur_rose = (zones[0].height * 2).min(zones[0].width / 2);
+    let haut = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(largeur_rose), Constraint::Min(0)].as_ref())
+        .split(zones[0]);
+
+    // Longueur de la flèche : au moins un tiers du rayon pour voir la
+    // direction d'un vent faible, le bord de la rose à 50 km/h (ou plus
+    // si les rafales dépassent)
+    let echelle = 50.0_f64.max(rafales).max(vitesse);
+    let longueur = |v: f64| -> f64 { 0.3 + 0.6 * (v / echelle).min(1.0) };
+    let direction = heure.wind_direction;
+    let rose = Canvas::default()
+        .paint(|ctx| {
+            let mut cercle = vec![];
+            for i in 0..=72 {
+                let angle = i as f64 * 5.0_f64.to_radians();
+                cercle.push((angle.sin(), angle.cos()));
+            }
+            for i in 1..cercle.len() {
+                ctx.draw(&Line {
+                    x1: cercle[i - 1].0,
+                    y1: cercle[i - 1].1,
+                    x2: cercle[i].0,
+                    y2: cercle[i].1,
+                    color: Color::DarkGray,
+                });
+            }
+            ctx.layer();
+
+            if let Some(direction) = direction {
+                // Le vent souffle à l'opposé de la direction d'où il vient
+                let angle = (direction + 180.0).to_radians();
+                let (dx, dy) = (angle.sin(), angle.cos());
+                let bout_rafales = longueur(rafales);
+                let bout = longueur(vitesse);
+                if bout_rafales > bout {
+                    ctx.draw(&Line {
+                        x1: dx * bout,
+                        y1: dy * bout,
+                        x2: dx * bout_rafales,
+                        y2: dy * bout_rafales,
+                        color: Color::LightRed,
+                    });
+                }
+                ctx.draw(&Line {
+                    x1: 0.0,
+                    y1: 0.0,
+                    x2: dx * bout,
+                    y2: dy * bout,
+                    color: couleur_vent(vitesse),
+                });
+                // Pointe de la flèche
+                for cote in [-0.5_f64, 0.5].iter() {
+                    let retour = angle + std::f64::consts::PI + cote;
+                    ctx.draw(&Line {
+                        x1: dx * bout,
+                        y1: dy * bout,
+                        x2: dx * bout + retour.sin() * 0.25,
+                        y2: dy * bout + retour.cos() * 0.25,
+                        color: couleur_vent(vitesse),
+                    });
+                }
+                // Origine du vent sur le bord de la rose
+                ctx.draw(&Points {
+                    coords: &[(-dx, -dy)],
+                    color: Color::Yellow,
+                });
+            }
+
+            ctx.print(
+                -0.05,
+                1.15,
+                Span::styled("N", Style::default().fg(Color::Gray)),
+            );
+            ctx.print(
+                -0.05,
+                -1.15,
+                Span::styled("S", Style::default().fg(Color::Gray)),
+            );
+            ctx.print(
+                1.1,
+                0.0,
+                Span::styled("E", Style::default().fg(Color::Gray)),
+            );
+            ctx.print(
+                -1.25,
+                0.0,
+                Span::styled("O", Style::default().fg(Color::Gray)),
+            );
+        })
+        .x_bounds([-1.3, 1.3])
+        .y_bounds([-1.3, 1.3]);
+    f.render_widget(rose, haut[0]);
+
+    let (force, nom_force) = beaufort(vitesse);
+    let origine = match direction {
+        Some(d) => format!("Vent de {} ({:.0}°)", nom_direction_vent(d), d),
+        None => match &heure.wind_icon {
+            Some(icon) => format!("Vent de {}", icon),
+            None => String::from("Direction inconnue"),
+        },
+    };
+    let texte = vec![
+        Spans::from(Span::styled(
+            origine,
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Spans::from(Span::styled(
+            format!("{:.0} km/h", vitesse),
+            Style::default()
+                .fg(couleur_vent(vitesse))
+                .add_modifier(Modifier::BOLD),
+        )),
+        Spans::from(vec![
+            Span::raw("Rafales "),
+            Span::styled(
+                if rafales > 0.0 {
+                    format!("{:.0} km/h", rafales)
+                } else {
+                    String::from("-")
+                },
+                Style::default().fg(Color::LightRed),
+            ),
+        ]),
+        Spans::from(format!("Force {} Beaufort", force)),
+        Spans::from(Span::styled(nom_force, Style::default().fg(Color::Gray))),
+    ];
+    f.render_widget(Paragraph::new(texte), haut[1]);
+
+    // Directions des 24 prochaines heures, une flèche par prévision
+    let fin = *dateheure + chrono::Duration::hours(24);
+    let mut fleches = vec![];
+    let mut graduations = String::new();
+    for (h, prevision) in heures[actuelle..].iter().filter(|(h, _)| *h < fin) {
+        if fleches.len() as u16 >= zones[1].width {
+            break;
+        }
+        let style = Style::default().fg(couleur_vent(vitesse_kmh(
+            prevision.wind_speed.unwrap_or(0.0),
+        )));
+        fleches.push(match prevision.wind_direction {
+            Some(d) => Span::styled(fleche_vent(d), style),
+            None => Span::raw(" "),
+        });
+        // Heure toutes les 6 heures, si elle a la place d'être écrite
+        if h.hour() % 6 == 0 && graduations.width() < fleches.len() {
+            let manque = fleches.len() - 1 - graduations.width();
+            graduations.push_str(&" ".repeat(manque));
+            graduations.push_str(&h.format("%Hh").to_string());
+        }
+    }
+    f.render_widget(
+        Paragraph::new(vec![
+            Spans::from(fleches),
+            Spans::from(Span::styled(graduations, Style::default().fg(Color::Gray))),
+        ]),
+        zones[1],
+    );
+}
+
+/// Fonction pour afficher le tableau des prévisions heure par heure de la
+/// ville affichée dans l'onglet Prévisions, avec une ligne des jours pour
+/// repérer le jour de la ligne sélectionnée
+fn onglet_horaire<B: Backend>(f: &mut Frame<B>, meteo_app: &mut MeteoApp, frame: Rect) {
+    let prev = match meteo_app.prevision.to_owned() {
+        Some(p) => p,
         None => {
             let message = "Aucune ville de renseignée ou de recherchée";
-            let block_no_place = Paragraph::new(message.as_ref()).style(Style::default());
-            f.render_widget(block_no_place, frame)
+            f.render_widget(Paragraph::new(message), frame);
+            return;
+        }
+    };
+    let heures = previsions_horaires(&prev);
+    let nom_ville = prev
+        .properties
+        .as_ref()
+        .and_then(|p| p.name.to_owned())
+        .unwrap_or(String::from("Ville"));
+
+    let zones = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Percentage(45),
+                Constraint::Length(7),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(frame);
+
+    if heures.is_empty() {
+        f.render_widget(
+            Paragraph::new("Pas de prévisions heure par heure pour cette ville.")
+                .block(Block::default().title(nom_ville).borders(Borders::ALL)),
+            frame,
+        );
+        return;
+    }
+    let selection = meteo_app
+        .etat_horaire
+        .selected()
+        .unwrap_or(0)
+        .min(heures.len() - 1);
+    meteo_app.etat_horaire.select(Some(selection));
+    let date_selection = heures[selection].0.date_naive();
+
+    // Ligne des jours
+    let mut jours = vec![];
+    let mut precedente = None;
+    for (dateheure, _) in &heures {
+        let date = dateheure.date_naive();
+        if precedente == Some(date) {
+            continue;
+        }
+        precedente = Some(date);
+        let style = if date == date_selection {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        jours.push(Span::styled(
+            format!(
+                " {} {} ",
+                get_nom_jour(date.weekday()),
+                date.format("%d/%m")
+            ),
+            style,
+        ));
+        jours.push(Span::raw(" "));
+    }
+    f.render_widget(
+        Paragraph::new(Spans::from(jours)).block(
+            Block::default()
+                .title(format!("{} (← → pour changer de jour)", nom_ville))
+                .borders(Borders::ALL),
+        ),
+        zones[0],
+    );
+
+    let ligne_graphiques = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(36)].as_ref())
+        .split(zones[1]);
+    meteogramme(f, &heures, selection, ligne_graphiques[0]);
+    bloc_pression(f, &heures, selection, ligne_graphiques[1]);
+    let creneaux = creneaux_risques(&prev);
+    // Créneau mis en valeur : survolé, sinon celui de l'heure sélectionnée
+    let creneau_selectionne = creneaux
+        .iter()
+        .rposition(|(debut, _)| *debut <= heures[selection].0)
+        .or(Some(0));
+    let creneau_actif = meteo_app
+        .creneau_survole
+        .filter(|i| *i < creneaux.len())
+        .or(creneau_selectionne);
+    meteo_app.zones.creneaux_risques =
+        tableau_risques(f, &creneaux, creneau_selectionne, creneau_actif, zones[2]);
+
+    // Tableau heure par heure
+    let style_titre = Style::default()
+        .fg(Color::LightMagenta)
+        .add_modifier(Modifier::BOLD);
+    let titres = Row::new(
+        [
+            "Heure",
+            "",
+            "Temps",
+            "T",
+            "Ressenti",
+            "Vent (km/h)",
+            "Pluie",
+            "Neige",
+            "Nuages",
+            "Humidité",
+            "Pression",
+        ]
+        .iter()
+        .map(|t| Cell::from(*t).style(style_titre)),
+    );
+
+    // Journées des prévisions, pour choisir l'icon de jour ou de nuit selon
+    // les heures de lever et de coucher du soleil
+    let journees = prev
+        .properties
+        .as_ref()
+        .and_then(|p| p.daily_forecast.to_owned())
+        .unwrap_or_default();
+    let mut precedente = None;
+    let lignes: Vec<Row> = heures
+        .iter()
+        .map(|(dateheure, heure)| {
+            // La date n'est écrite que sur la première heure de chaque jour
+            let date = dateheure.date_naive();
+            let jour = if precedente == Some(date) {
+                String::from("       ")
+            } else {
+                format!("{} {}", get_nom_jour(date.weekday()), date.format("%d"))
+            };
+            precedente = Some(date);
+
+            let code = heure.weather_icon.to_owned().unwrap_or_default();
+            let nuit = journees
+                .iter()
+                .find(|journee| {
+                    let debut = get_dateheure_from_str(journee.time.to_owned().unwrap_or_default());
+                    debut.date_naive() == date
+                })
+                .and_then(|journee| {
+                    est_de_nuit(heure.time.to_owned().unwrap_or_default(), journee)
+                });
+            let icon = match nuit {
+                Some(nuit) => ascii_icons::get_type_icon_moment(&code, nuit),
+                None => ascii_icons::get_type_icon_from_str(&code),
+            };
+            let vent = match heure.wind_speed {
+                Some(vitesse) => {
+                    // Sans direction, la flèche est laissée vide
+                    let fleche = match heure.wind_direction {
+                        Some(direction) => fleche_vent(direction),
+                        None => " ",
+                    };
+                    let mut texte = format!("{} {:>3.0}", fleche, vitesse_kmh(vitesse));
+                    match heure.wind_speed_gust {
+                        Some(rafale) if rafale > 0.0 => {
+                            texte.push_str(&format!(" raf. {:.0}", vitesse_kmh(rafale)))
+                        }
+                        _ => {}
+                    }
+                    texte
+                }
+                None => String::from("-"),
+            };
+            let optionnel = |valeur: Option<f64>, format: &dyn Fn(f64) -> String| match valeur {
+                Some(v) => format(v),
+                None => String::from("-"),
+            };
+
+            Row::new(vec![
+                Cell::from(format!("{} {}", jour, dateheure.format("%Hh"))),
+                Cell::from(ascii_icons::get_glyphe_aligne(icon, meteo_app.symboles)),
+                Cell::from(heure.weather_description.to_owned().unwrap_or_default()),
+                Cell::from(optionnel(heure.t, &|t| format!("{:>5.1}°", t)))
+                    .style(Style::default().fg(couleur_temperature(heure.t.unwrap_or(0.0)))),
+                Cell::from(optionnel(heure.t_windchill, &|t| format!("{:>5.1}°", t))),
+                Cell::from(vent),
+                Cell::from(optionnel(heure.rain_1h, &|r| format!("{:>4.1} mm", r))),
+                Cell::from(optionnel(heure.snow_1h, &|n| format!("{:>4.1} cm", n))),
+                Cell::from(optionnel(heure.total_cloud_cover.map(|n| n as f64), &|n| {
+                    format!("{:>3.0} %", n)
+                })),
+                Cell::from(optionnel(heure.relative_humidity.map(|h| h as f64), &|h| {
+                    format!("{:>3.0} %", h)
+                })),
+                Cell::from(optionnel(heure.p_sea, &|p| format!("{:>6.1} hPa", p))),
+            ])
+        })
+        .collect();
+
+    let largeurs = [
+        Constraint::Length(11),
+        Constraint::Length(2),
+        Constraint::Min(12),
+        Constraint::Length(6),
+        Constraint::Length(8),
+        Constraint::Length(15),
+        Constraint::Length(7),
+        Constraint::Length(7),
+        Constraint::Length(6),
+        Constraint::Length(8),
+        Constraint::Length(10),
+    ];
+    let tableau = Table::new(lignes)
+        .header(titres)
+        .widths(&largeurs)
+        .column_spacing(1)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .block(
+            Block::default()
+                .title("Heure par heure (↑ ↓ PAGE ↑ PAGE ↓ pour défiler)")
+                .borders(Borders::ALL),
+        );
+    meteo_app.zones.tableau_horaire = zones[3];
+    f.render_stateful_widget(tableau, zones[3], &mut meteo_app.etat_horaire);
+}
+
+/// Largeur des graduations à gauche et à droite du météogramme
+const MARGE_METEOGRAMME: u16 = 6;
+
+/// Intensité des précipitations d'une heure, en mm/h. Au-delà des
+/// premières heures, l'API ne donne plus que des cumuls sur 3 ou 6 heures.
+fn intensite_pluie(heure: &Forecast) -> Option<f64> {
+    match (heure.rain_1h, heure.rain_3h, heure.rain_6h) {
+        (Some(pluie), _, _) => Some(pluie),
+        (None, Some(pluie), _) => Some(pluie / 3.0),
+        (None, None, Some(pluie)) => Some(pluie / 6.0),
+        _ => None,
+    }
+}
+
+/// Caractère de la bande de couverture nuageuse, du ciel clair au couvert
+fn caractere_nuages(couverture: i64) -> &'static str {
+    match couverture {
+        i64::MIN..=12 => " ",
+        13..=37 => "░",
+        38..=62 => "▒",
+        63..=87 => "▓",
+        _ => "█",
+    }
+}
+
+/// Couleur d'une flèche de vent selon sa vitesse (en km/h)
+fn couleur_vent(vitesse: f64) -> Color {
+    match vitesse as i64 {
+        i64::MIN..=19 => Color::Green,
+        20..=39 => Color::Yellow,
+        40..=59 => Color::LightRed,
+        _ => Color::Red,
+    }
+}
+
+/// Ligne de texte du météogramme : une case par colonne du graphique,
+/// précédée de son libellé dans la marge de gauche
+fn ligne_meteogramme<'a>(libelle: &'a str, cases: Vec<(&'a str, Style)>) -> Spans<'a> {
+    let mut spans = vec![Span::styled(
+        format!("{:<1$}", libelle, MARGE_METEOGRAMME as usize),
+        Style::default().fg(Color::Gray),
+    )];
+    for (texte, style) in cases {
+        spans.push(Span::styled(texte, style));
+    }
+    Spans::from(spans)
+}
+
+/// Fonction pour afficher le météogramme des prévisions heure par heure :
+/// courbe des températures, barres de précipitations (axe de droite),
+/// flèches du vent, bande de couverture nuageuse et heures du lieu, avec
+/// un trait à chaque changement de jour et sur l'heure sélectionnée
+fn meteogramme<B: Backend>(
+    f: &mut Frame<B>,
+    heures: &[(DateTime<FixedOffset>, Forecast)],
+    selection: usize,
+    zone: Rect,
+) {
+    let titre = Spans::from(vec![
+        Span::raw("Météogramme "),
+        Span::styled("━ température ", Style::default().fg(Color::Red)),
+        Span::styled("█ précipitations (mm/h)", Style::default().fg(Color::Blue)),
+    ]);
+    let bloc = Block::default().title(titre).borders(Borders::ALL);
+    let interieur = bloc.inner(zone);
+    f.render_widget(bloc, zone);
+    if heures.is_empty() || interieur.width <= 2 * MARGE_METEOGRAMME + 4 || interieur.height < 6 {
+        return;
+    }
+
+    // Abscisses : nombre d'heures depuis la première prévision (les pas
+    // de temps s'espacent au-delà des premières heures)
+    let debut = heures[0].0;
+    let abscisses: Vec<f64> = heures
+        .iter()
+        .map(|(dateheure, _)| (*dateheure - debut).num_minutes() as f64 / 60.0)
+        .collect();
+    let x_min = -0.5;
+    let x_max = abscisses[abscisses.len() - 1] + 0.5;
+    let largeur = interieur.width - 2 * MARGE_METEOGRAMME;
+    let colonne = |x: f64| -> usize {
+        let c = (x - x_min) / (x_max - x_min) * largeur as f64;
+        (c.max(0.0) as usize).min(largeur as usize - 1)
+    };
+    // Prévision la plus proche de chaque colonne
+    let plus_proche = |c: usize| -> usize {
+        let x = x_min + (c as f64 + 0.5) / largeur as f64 * (x_max - x_min);
+        let mut meilleure = 0;
+        for (i, a) in abscisses.iter().enumerate() {
+            if (a - x).abs() < (abscisses[meilleure] - x).abs() {
+                meilleure = i;
+            }
+        }
+        meilleure
+    };
+
+    // Bornes des températures et des précipitations
+    let temperatures: Vec<f64> = heures.iter().filter_map(|(_, h)| h.t).collect();
+    let t_min = temperatures.iter().cloned().fold(f64::INFINITY, f64::min);
+    let t_max = temperatures
+        .iter()
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (mut y_min, mut y_max) = if temperatures.is_empty() {
+        (0.0, 10.0)
+    } else {
+        (t_min.floor() - 1.0, t_max.ceil() + 1.0)
+    };
+    if y_max - y_min < 4.0 {
+        let milieu = (y_max + y_min) / 2.0;
+        y_min = (milieu - 2.0).floor();
+        y_max = y_min + 4.0;
+    }
+    let pluie_max = heures
+        .iter()
+        .filter_map(|(_, h)| intensite_pluie(h))
+        .fold(1.0, f64::max)
+        .ceil();
+
+    let hauteur_graphique = interieur.height - 3;
+    let graphique = Rect::new(
+        interieur.x + MARGE_METEOGRAMME,
+        interieur.y,
+        largeur,
+        hauteur_graphique,
+    );
+
+    // Graduations des deux axes
+    let mut gauche = vec![];
+    let mut droite = vec![];
+    for ligne in 0..hauteur_graphique {
+        let (temperature, pluie) = if ligne == 0 {
+            (Some(y_max), Some(pluie_max))
+        } else if ligne == hauteur_graphique - 1 {
+            (Some(y_min), Some(0.0))
+        } else if ligne == hauteur_graphique / 2 && hauteur_graphique >= 5 {
+            (Some((y_max + y_min) / 2.0), Some(pluie_max / 2.0))
+        } else {
+            (None, None)
+        };
+        gauche.push(Spans::from(Span::styled(
+            match temperature {
+                Some(t) => format!("{:>4.0}° ", t),
+                None => String::new(),
+            },
+            Style::default().fg(Color::Red),
+        )));
+        droite.push(Spans::from(Span::styled(
+            match pluie {
+                Some(p) => format!(" {:<4}", format!("{:.1}", p)),
+                None => String::new(),
+            },
+            Style::default().fg(Color::Blue),
+        )));
+    }
+    f.render_widget(
+        Paragraph::new(gauche),
+        Rect::new(
+            interieur.x,
+            interieur.y,
+            MARGE_METEOGRAMME,
+            hauteur_graphique,
+        ),
+    );
+    f.render_widget(
+        Paragraph::new(droite),
+        Rect::new(
+            graphique.x + largeur,
+            interieur.y,
+            MARGE_METEOGRAMME,
+            hauteur_graphique,
+        ),
+    );
+
+    // Séparateurs des jours, à minuit heure du lieu
+    let mut separateurs = vec![];
+    for i in 1..heures.len() {
+        let (dateheure, _) = &heures[i];
+        if dateheure.date_naive() != heures[i - 1].0.date_naive() {
+            let depuis_minuit = dateheure.hour() as f64 + dateheure.minute() as f64 / 60.0;
+            separateurs.push((abscisses[i] - depuis_minuit, dateheure.date_naive()));
+        }
+    }
+
+    let pas_barre = (x_max - x_min) / (largeur as f64 * 4.0);
+    let selectionnee = abscisses[selection.min(abscisses.len() - 1)];
+    let canvas = Canvas::default()
+        .paint(|ctx| {
+            for (x, _) in &separateurs {
+                ctx.draw(&Line {
+                    x1: *x,
+                    y1: y_min,
+                    x2: *x,
+                    y2: y_max,
+                    color: Color::DarkGray,
+                });
+            }
+            ctx.draw(&Line {
+                x1: selectionnee,
+                y1: y_min,
+                x2: selectionnee,
+                y2: y_max,
+                color: Color::Gray,
+            });
+            if y_min < 0.0 && y_max > 0.0 {
+                ctx.draw(&Line {
+                    x1: x_min,
+                    y1: 0.0,
+                    x2: x_max,
+                    y2: 0.0,
+                    color: Color::DarkGray,
+                });
+            }
+            ctx.layer();
+
+            // Barres de précipitations, sur la durée couverte par chaque prévision
+            for (i, (_, heure)) in heures.iter().enumerate() {
+                let pluie = intensite_pluie(heure).unwrap_or(0.0);
+                if pluie <= 0.0 {
+                    continue;
+                }
+                let duree = if i > 0 {
+                    abscisses[i] - abscisses[i - 1]
+                } else {
+                    1.0
+                };
+                let haut = y_min + pluie / pluie_max * (y_max - y_min);
+                let couleur = if heure.snow_1h.unwrap_or(0.0) > 0.0 {
+                    Color::LightCyan
+                } else {
+                    Color::Blue
+                };
+                let mut x = abscisses[i] - duree * 0.3;
+                while x <= abscisses[i] + duree * 0.3 {
+                    ctx.draw(&Line {
+                        x1: x,
+                        y1: y_min,
+                        x2: x,
+                        y2: haut,
+                        color: couleur,
+                    });
+                    x += pas_barre;
+                }
+            }
+            ctx.layer();
+
+            // Courbe des températures, en rouge au-dessus de zéro et en bleu en dessous
+            for i in 1..heures.len() {
+                if let (Some(t1), Some(t2)) = (heures[i - 1].1.t, heures[i].1.t) {
+                    ctx.draw(&Line {
+                        x1: abscisses[i - 1],
+                        y1: t1,
+                        x2: abscisses[i],
+                        y2: t2,
+                        color: if t1 + t2 >= 0.0 {
+                            Color::Red
+                        } else {
+                            Color::LightBlue
+                        },
+                    });
+                }
+            }
+
+            for (x, date) in &separateurs {
+                ctx.print(
+                    *x,
+                    y_max,
+                    Span::styled(
+                        format!(" {} {}", get_nom_jour(date.weekday()), date.format("%d")),
+                        Style::default().fg(Color::Gray),
+                    ),
+                );
+            }
+        })
+        .x_bounds([x_min, x_max])
+        .y_bounds([y_min, y_max]);
+    f.render_widget(canvas, graphique);
+
+    // Flèches du vent, espacées d'au moins une case
+    let mut vent = vec![(" ", Style::default()); largeur as usize];
+    let mut derniere: Option<usize> = None;
+    for (i, (_, heure)) in heures.iter().enumerate() {
+        let c = colonne(abscisses[i]);
+        if derniere.map(|d| c < d + 2).unwrap_or(false) {
+            continue;
+        }
+        if let (Some(vitesse), Some(direction)) = (heure.wind_speed, heure.wind_direction) {
+            vent[c] = (
+                fleche_vent(direction),
+                Style::default().fg(couleur_vent(vitesse_kmh(vitesse))),
+            );
+            derniere = Some(c);
+        }
+    }
+
+    // Bande de couverture nuageuse
+    let nuages = (0..largeur as usize)
+        .map(|c| {
+            let couverture = heures[plus_proche(c)].1.total_cloud_cover;
+            match couverture {
+                Some(n) => (caractere_nuages(n), Style::default().fg(Color::Gray)),
+                None => (" ", Style::default()),
+            }
+        })
+        .collect();
+
+    // Heures du lieu, avec un pas qui laisse la place d'écrire "06h"
+    let cases_par_heure = largeur as f64 / (x_max - x_min);
+    let pas_heures = vec![1, 2, 3, 6, 12, 24]
+        .into_iter()
+        .find(|pas| *pas as f64 * cases_par_heure >= 4.0)
+        .unwrap_or(24);
+    let mut graduations = vec![String::from(" "); largeur as usize];
+    let mut fin_derniere = 0;
+    for (i, (dateheure, _)) in heures.iter().enumerate() {
+        if dateheure.hour() % pas_heures != 0 || dateheure.minute() != 0 {
+            continue;
         }
+        let c = colonne(abscisses[i]);
+        if c < fin_derniere || c + 3 > largeur as usize {
+            continue;
+        }
+        for (j, caractere) in format!("{:02}h", dateheure.hour()).chars().enumerate() {
+            graduations[c + j] = caractere.to_string();
+        }
+        fin_derniere = c + 4;
+    }
+    let graduations = graduations
+        .iter()
+        .map(|g| (g.as_str(), Style::default().fg(Color::Gray)))
+        .collect();
+
+    f.render_widget(
+        Paragraph::new(vec![
+            ligne_meteogramme("Vent", vent),
+            ligne_meteogramme("Nuages", nuages),
+            ligne_meteogramme("", graduations),
+        ]),
+        Rect::new(
+            interieur.x,
+            interieur.y + hauteur_graphique,
+            interieur.width - MARGE_METEOGRAMME,
+            3,
+        ),
+    );
+}
+
+/// Créneaux de la prévision des risques (pluie, neige, verglas, orage),
+/// avec leur date et heure de début dans le fuseau du lieu
+fn creneaux_risques(prev: &Prevision) -> Vec<(DateTime<FixedOffset>, ProbabilityForecast)> {
+    let properties = match &prev.properties {
+        Some(p) => p,
+        None => return vec![],
+    };
+    properties
+        .probability_forecast
+        .to_owned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|creneau| {
+            let dateheure = get_dateheure_locale(
+                creneau.time.to_owned().unwrap_or_default(),
+                &properties.timezone,
+            );
+            (dateheure, creneau)
+        })
+        .collect()
+}
+
+/// Risques d'un créneau, dans l'ordre des lignes du tableau : pluie, neige,
+/// verglas et orage. Au-delà des premiers jours, l'API ne donne plus la
+/// pluie et la neige que sur 6 heures.
+fn risques(creneau: &ProbabilityForecast) -> [Option<i64>; 4] {
+    [
+        creneau.rain_hazard_3h.or(creneau.rain_hazard_6h),
+        creneau.snow_hazard_3h.or(creneau.snow_hazard_6h),
+        creneau.freezing_hazard,
+        creneau.storm_hazard,
+    ]
+}
+
+/// Noms des lignes du tableau des risques
+const NOMS_RISQUES: [&str; 4] = ["Pluie", "Neige", "Verglas", "Orage"];
+
+/// Couleur d'une case du tableau des risques selon la probabilité (en %)
+fn couleur_risque(probabilite: i64) -> Color {
+    match probabilite {
+        i64::MIN..=0 => Color::Reset,
+        1..=29 => Color::Green,
+        30..=49 => Color::Yellow,
+        50..=69 => Color::LightRed,
+        _ => Color::Red,
+    }
+}
+
+/// Fonction pour afficher le tableau des risques : une ligne par risque,
+/// une colonne par créneau, colorée selon la probabilité. Les pourcentages
+/// sont écrits pour le créneau actif (survolé ou sélectionné) et rappelés
+/// en marge ; sans créneau actif, ils sont écrits partout et la marge donne
+/// le maximum. Les créneaux affichés suivent le créneau sélectionné
+/// seulement, pour que le survol ne fasse pas défiler le tableau.
+/// Renvoie les zones des colonnes affichées.
+fn tableau_risques<B: Backend>(
+    f: &mut Frame<B>,
+    creneaux: &[(DateTime<FixedOffset>, ProbabilityForecast)],
+    selectionne: Option<usize>,
+    actif: Option<usize>,
+    zone: Rect,
+) -> Vec<(usize, Rect)> {
+    const MARGE: u16 = 13;
+    let bloc = Block::default().borders(Borders::ALL);
+    let interieur = bloc.inner(zone);
+    if creneaux.is_empty() || interieur.width <= MARGE + 3 || interieur.height < 5 {
+        f.render_widget(bloc.title("Risques"), zone);
+        return vec![];
+    }
+
+    let largeur_colonne = ((interieur.width - MARGE) / creneaux.len() as u16).clamp(3, 6);
+    let visibles = ((interieur.width - MARGE) / largeur_colonne) as usize;
+    let premier = match selectionne {
+        Some(s) if s >= visibles => s + 1 - visibles,
+        _ => 0,
+    };
+    let derniere = (premier + visibles).min(creneaux.len());
+
+    let titre = match actif {
+        Some(a) => {
+            let debut_actif = creneaux[a].0;
+            let fin_actif = match creneaux.get(a + 1) {
+                Some((fin, _)) => fin.format("%Hh").to_string(),
+                None => (debut_actif + chrono::Duration::hours(3))
+                    .format("%Hh")
+                    .to_string(),
+            };
+            format!(
+                "Risques {} {} {}-{}",
+                get_nom_jour(debut_actif.weekday()),
+                debut_actif.format("%d"),
+                debut_actif.format("%Hh"),
+                fin_actif
+            )
+        }
+        None => String::from("Risques"),
+    };
+    f.render_widget(bloc.title(titre), zone);
+
+    // Ligne des heures, avec le jour au premier créneau de chaque jour
+    let mut entete = vec![Span::raw(" ".repeat(MARGE as usize))];
+    for (i, (debut, _)) in creneaux.iter().enumerate().take(derniere).skip(premier) {
+        let nouveau_jour = i == 0 || creneaux[i - 1].0.date_naive() != debut.date_naive();
+        let texte = if nouveau_jour && largeur_colonne >= 5 {
+            get_nom_jour(debut.weekday()).to_string()
+        } else {
+            debut.format("%Hh").to_string()
+        };
+        let mut style = Style::default().fg(Color::Gray);
+        if nouveau_jour {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if Some(i) == actif {
+            style = style.fg(Color::Yellow);
+        }
+        entete.push(Span::styled(
+            format!("{:<1$}", texte, largeur_colonne as usize),
+            style,
+        ));
+    }
+    let mut lignes = vec![Spans::from(entete)];
+
+    for (r, nom) in NOMS_RISQUES.iter().enumerate() {
+        let valeur = match actif {
+            Some(a) => risques(&creneaux[a].1)[r],
+            None => creneaux.iter().filter_map(|(_, c)| risques(c)[r]).max(),
+        };
+        let pourcentage = match valeur {
+            Some(p) => format!("{:>3} %", p),
+            None => String::from("    -"),
+        };
+        let mut spans = vec![Span::raw(format!("{:<8}{} ", nom, pourcentage))];
+        for (i, (_, creneau)) in creneaux.iter().enumerate().take(derniere).skip(premier) {
+            let probabilite = risques(creneau)[r];
+            let ecrit = actif.is_none() || Some(i) == actif;
+            let texte = match probabilite {
+                Some(p) if ecrit && (p > 0 || Some(i) == actif) => {
+                    format!("{:^1$}", p, largeur_colonne as usize - 1)
+                }
+                Some(p) if p > 0 => " ".repeat(largeur_colonne as usize - 1),
+                Some(_) => format!("{:^1$}", "·", largeur_colonne as usize - 1),
+                None => format!("{:^1$}", "-", largeur_colonne as usize - 1),
+            };
+            let mut style = Style::default()
+                .bg(couleur_risque(probabilite.unwrap_or(0)))
+                .fg(Color::Black);
+            if probabilite.unwrap_or(0) <= 0 {
+                style = style.fg(Color::DarkGray);
+            }
+            if Some(i) == actif {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            spans.push(Span::styled(texte, style));
+            spans.push(Span::raw(" "));
+        }
+        lignes.push(Spans::from(spans));
+    }
+    f.render_widget(Paragraph::new(lignes), interieur);
+
+    (premier..derniere)
+        .map(|i| {
+            let x = interieur.x + MARGE + (i - premier) as u16 * largeur_colonne;
+            (
+                i,
+                Rect::new(x, interieur.y, largeur_colonne, interieur.height),
+            )
+        })
+        .collect()
+}
+
+/// Tendance de la pression sur 3 heures (en hPa), comme celle des bulletins
+/// météo marins
+fn nom_tendance_pression(variation: f64) -> &'static str {
+    match variation {
+        v if v <= -6.0 => "en baisse très rapide",
+        v if v <= -3.6 => "en baisse rapide",
+        v if v <= -1.6 => "en baisse",
+        v if v < -0.1 => "en légère baisse",
+        v if v <= 0.1 => "stable",
+        v if v < 1.6 => "en légère hausse",
+        v if v < 3.6 => "en hausse",
+        v if v < 6.0 => "en hausse rapide",
+        _ => "en hausse très rapide",
+    }
+}
+
+/// Variation de la pression (en hPa) sur 3 heures, depuis la prévision la
+/// plus proche datant d'au moins 3 heures : les prévisions sont horaires,
+/// puis toutes les 3 ou 6 heures, où la variation est ramenée à 3 heures.
+/// Sans prévision assez ancienne, la tendance est inconnue.
+fn variation_pression(
+    heures: &[(DateTime<FixedOffset>, Forecast)],
+    selection: usize,
+) -> Option<f64> {
+    let (dateheure, heure) = heures.get(selection)?;
+    let pression = heure.p_sea?;
+    let limite = *dateheure - chrono::Duration::hours(3);
+    let (avant, pression_avant) = heures[..selection]
+        .iter()
+        .rev()
+        .filter(|(h, _)| *h <= limite)
+        .find_map(|(h, prevision)| prevision.p_sea.map(|p| (*h, p)))?;
+    let ecart = (*dateheure - avant).num_minutes() as f64 / 60.0;
+    if ecart > 3.0 {
+        return Some((pression - pression_avant) * 3.0 / ecart);
+    }
+    Some(pression - pression_avant)
+}
+
+/// Lecture d'un baromètre à aiguille : la zone du cadran est donnée par la
+/// pression, puis décalée d'un cran lorsque la pression varie vite (si la
+/// tendance est connue)
+fn lecture_barometre(pression: f64, variation: Option<f64>) -> (&'static str, Color) {
+    const CADRAN: [(&str, Color); 4] = [
+        ("tempête", Color::Red),
+        ("pluie", Color::LightBlue),
+        ("variable", Color::Gray),
+        ("beau", Color::Yellow),
+    ];
+    let mut zone: i32 = match pression {
+        p if p < 990.0 => 0,
+        p if p < 1005.0 => 1,
+        p if p < 1020.0 => 2,
+        _ => 3,
+    };
+    match variation {
+        Some(v) if v <= -1.6 => zone -= 1,
+        Some(v) if v >= 1.6 => zone += 1,
+        _ => {}
+    }
+    CADRAN[zone.clamp(0, 3) as usize]
+}
+
+/// Fonction pour afficher l'évolution de la pression au niveau de la mer,
+/// avec la pression de l'heure sélectionnée, sa tendance sur 3 heures et
+/// la lecture du baromètre qui en découle
+fn bloc_pression<B: Backend>(
+    f: &mut Frame<B>,
+    heures: &[(DateTime<FixedOffset>, Forecast)],
+    selection: usize,
+    zone: Rect,
+) {
+    let bloc = Block::default().title("Pression").borders(Borders::ALL);
+    let interieur = bloc.inner(zone);
+    f.render_widget(bloc, zone);
+
+    let debut = match heures.first() {
+        Some((dateheure, _)) => *dateheure,
+        None => return,
+    };
+    let abscisse = |dateheure: &DateTime<FixedOffset>| -> f64 {
+        (*dateheure - debut).num_minutes() as f64 / 60.0
+    };
+    let points: Vec<(f64, f64)> = heures
+        .iter()
+        .filter_map(|(dateheure, heure)| heure.p_sea.map(|p| (abscisse(dateheure), p)))
+        .collect();
+    let selection = selection.min(heures.len() - 1);
+    let (dateheure, heure) = &heures[selection];
+    let pression = match heure.p_sea {
+        Some(p) if points.len() >= 2 => p,
+        _ => {
+            f.render_widget(
+                Paragraph::new("Pas de prévision de la pression."),
+                interieur,
+            );
+            return;
+        }
+    };
+
+    let variation = variation_pression(heures, selection);
+    let tendance = match variation {
+        Some(v) => {
+            let nom = nom_tendance_pression(v);
+            format!(
+                "{}{} ({:+.1} hPa en 3 h)",
+                nom[..1].to_uppercase(),
+                &nom[1..],
+                v
+            )
+        }
+        None => String::from("Tendance inconnue"),
+    };
+    let (lecture, couleur_lecture) = lecture_barometre(pression, variation);
+
+    let zones = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+        .split(interieur);
+
+    let p_min = points
+        .iter()
+        .map(|(_, p)| *p)
+        .fold(f64::INFINITY, f64::min)
+        .floor()
+        - 1.0;
+    let p_max = points
+        .iter()
+        .map(|(_, p)| *p)
+        .fold(f64::NEG_INFINITY, f64::max)
+        .ceil()
+        + 1.0;
+    let x_max = points[points.len() - 1].0;
+    let x_selection = abscisse(dateheure);
+    let trait_selection = [(x_selection, p_min), (x_selection, p_max)];
+    let graphique = Chart::new(vec![
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::DarkGray))
+            .data(&trait_selection),
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::LightCyan))
+            .data(&points),
+    ])
+    .x_axis(Axis::default().bounds([0.0, x_max]))
+    .y_axis(
+        Axis::default()
+            .bounds([p_min, p_max])
+            .style(Style::default().fg(Color::Gray))
+            .labels(vec![
+                Span::raw(format!("{:.0}", p_min)),
+                Span::raw(format!("{:.0}", p_max)),
+            ]),
+    );
+    f.render_widget(graphique, zones[0]);
+
+    let texte = vec![
+        Spans::from(vec![
+            Span::styled(
+                format!("{:.0} hPa", pression),
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" à {}", dateheure.format("%Hh")),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Spans::from(tendance),
+        Spans::from(vec![
+            Span::raw("Baromètre : "),
+            Span::styled(
+                lecture,
+                Style::default()
+                    .fg(couleur_lecture)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+    f.render_widget(Paragraph::new(texte), zones[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn echelle_de_beaufort() {
+        assert_eq!(beaufort(0.0), (0, "calme"));
+        assert_eq!(beaufort(0.9), (0, "calme"));
+        assert_eq!(beaufort(1.0), (1, "très légère brise"));
+        assert_eq!(beaufort(25.0), (4, "jolie brise"));
+        assert_eq!(beaufort(117.9), (11, "violente tempête"));
+        assert_eq!(beaufort(118.0), (12, "ouragan"));
+        assert_eq!(beaufort(250.0), (12, "ouragan"));
+    }
+
+    #[test]
+    fn noms_des_tendances_de_pression() {
+        assert_eq!(nom_tendance_pression(0.0), "stable");
+        assert_eq!(nom_tendance_pression(0.1), "stable");
+        assert_eq!(nom_tendance_pression(-0.1), "stable");
+        assert_eq!(nom_tendance_pression(-1.0), "en légère baisse");
+        assert_eq!(nom_tendance_pression(-1.6), "en baisse");
+        assert_eq!(nom_tendance_pression(-4.0), "en baisse rapide");
+        assert_eq!(nom_tendance_pression(-8.0), "en baisse très rapide");
+        assert_eq!(nom_tendance_pression(1.0), "en légère hausse");
+        assert_eq!(nom_tendance_pression(2.0), "en hausse");
+        assert_eq!(nom_tendance_pression(4.0), "en hausse rapide");
+        assert_eq!(nom_tendance_pression(6.0), "en hausse très rapide");
+    }
+
+    fn heures_pression(pressions: &[(i64, Option<f64>)]) -> Vec<(DateTime<FixedOffset>, Forecast)> {
+        let debut = get_dateheure_locale(
+            String::from("2026-01-15T00:00:00Z"),
+            &Some(String::from("Europe/Paris")),
+        );
+        pressions
+            .iter()
+            .map(|(heure, pression)| {
+                let mut prevision = Forecast::new();
+                prevision.p_sea = *pression;
+                (debut + chrono::Duration::hours(*heure), prevision)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn variation_de_pression_horaire() {
+        // Prévisions horaires : la variation est lue 3 heures plus tôt
+        let heures = heures_pression(&[
+            (0, Some(1012.0)),
+            (1, Some(1011.0)),
+            (2, Some(1010.5)),
+            (3, Some(1010.4)),
+            (4, Some(1010.0)),
+        ]);
+        assert_eq!(variation_pression(&heures, 4), Some(-1.0));
+        assert_eq!(variation_pression(&heures, 2), None);
+    }
+
+    #[test]
+    fn variation_de_pression_ramenee_a_3_heures() {
+        // Prévisions toutes les 6 heures : la variation est divisée par deux
+        let heures = heures_pression(&[(48, Some(1010.0)), (54, Some(1014.0))]);
+        assert_eq!(variation_pression(&heures, 1), Some(2.0));
+
+        // La prévision précédente sans pression est sautée
+        let heures = heures_pression(&[(0, Some(1010.0)), (3, None), (6, Some(1016.0))]);
+        assert_eq!(variation_pression(&heures, 2), Some(3.0));
+    }
+
+    #[test]
+    fn variation_de_pression_inconnue() {
+        let heures = heures_pression(&[(0, Some(1010.0)), (1, None), (2, Some(1012.0))]);
+        assert_eq!(variation_pression(&heures, 0), None);
+        assert_eq!(variation_pression(&heures, 1), None);
+        assert_eq!(variation_pression(&heures, 5), None);
+    }
+
+    #[test]
+    fn barometre_sans_tendance() {
+        assert_eq!(lecture_barometre(1010.0, None).0, "variable");
+        assert_eq!(lecture_barometre(1010.0, Some(-2.0)).0, "pluie");
+        assert_eq!(lecture_barometre(1010.0, Some(0.0)).0, "variable");
     }
 }
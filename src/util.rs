@@ -3,25 +3,27 @@
 pub mod OptionJSONUtils {
     pub fn value_to_string(val: serde_json::Value) -> Option<String> {
         if !val.is_null() && val.is_string() {
-            return Some(val.as_str().unwrap().to_string());
+            Some(val.as_str().unwrap().to_string())
         } else {
-            return None;
+            None
         }
     }
 
     pub fn value_to_f64(val: serde_json::Value) -> Option<f64> {
-        if !val.is_null() && val.is_f64() {
-            return Some(val.to_string().parse::<f64>().unwrap_or(0.0));
+        // Les nombres entiers (direction du vent, rafales nulles...) sont
+        // aussi acceptés
+        if !val.is_null() && val.is_number() {
+            val.as_f64()
         } else {
-            return None;
+            None
         }
     }
 
     pub fn value_to_i64(val: serde_json::Value) -> Option<i64> {
         if !val.is_null() && val.is_i64() {
-            return Some(val.to_string().parse::<i64>().unwrap_or(0));
+            Some(val.to_string().parse::<i64>().unwrap_or(0))
         } else {
-            return None;
+            None
         }
     }
 }
@@ -29,7 +31,12 @@ pub mod OptionJSONUtils {
 #[allow(non_snake_case)]
 pub mod DateHeure {
 
-    use chrono::{DateTime, Utc};
+    use chrono::{DateTime, FixedOffset, Offset, TimeZone, Utc, Weekday};
+    use chrono_tz::Tz;
+    use std::collections::HashSet;
+    use std::sync::{Mutex, OnceLock};
+
+    use crate::storage;
 
     pub fn get_dateheure_from_str(string: String) -> DateTime<Utc> {
         let parse = string.parse::<DateTime<Utc>>();
@@ -38,4 +45,56 @@ pub mod DateHeure {
             Err(_) => Utc::now(),
         }
     }
+
+    /// Décalage horaire d'un fuseau (ex : "Europe/Paris") à un instant donné,
+    /// d'après la base de données des fuseaux (heure d'été comprise).
+    /// Un fuseau inconnu est noté dans le journal (une seule fois par session)
+    /// et les heures restent en UTC, comme elles sont renvoyées par l'API.
+    pub fn get_decalage(timezone: &str, instant: DateTime<Utc>) -> FixedOffset {
+        match timezone.parse::<Tz>() {
+            Ok(fuseau) => fuseau.offset_from_utc_datetime(&instant.naive_utc()).fix(),
+            Err(_) => {
+                signaler_fuseau_inconnu(timezone);
+                FixedOffset::east_opt(0).unwrap()
+            }
+        }
+    }
+
+    fn signaler_fuseau_inconnu(timezone: &str) {
+        static SIGNALES: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+        let signales = SIGNALES.get_or_init(|| Mutex::new(HashSet::new()));
+        let nouveau = match signales.lock() {
+            Ok(mut s) => s.insert(timezone.to_string()),
+            Err(_) => false,
+        };
+        if nouveau {
+            let _ = storage::journaliser_erreur(&format!(
+                "fuseau horaire inconnu : {}, heures affichées en UTC",
+                timezone
+            ));
+        }
+    }
+
+    /// Abréviation française d'un jour de la semaine (ex : "lun.")
+    pub fn get_nom_jour(jour: Weekday) -> &'static str {
+        match jour {
+            Weekday::Mon => "lun.",
+            Weekday::Tue => "mar.",
+            Weekday::Wed => "mer.",
+            Weekday::Thu => "jeu.",
+            Weekday::Fri => "ven.",
+            Weekday::Sat => "sam.",
+            Weekday::Sun => "dim.",
+        }
+    }
+
+    /// Date et heure dans le fuseau du lieu de la prévision
+    pub fn get_dateheure_locale(
+        string: String,
+        timezone: &Option<String>,
+    ) -> DateTime<FixedOffset> {
+        let utc = get_dateheure_from_str(string);
+        let fuseau = timezone.to_owned().unwrap_or(String::from("Europe/Paris"));
+        utc.with_timezone(&get_decalage(fuseau.as_str(), utc))
+    }
 }